@@ -0,0 +1,53 @@
+// Montgomery exponentiation against mod_exp, which divides after every product.
+// Run with `cargo bench --bench montgomery`.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use euclidean::mod_exp;
+use euclidean::montgomery::{ModularContext, Montgomery};
+use euclidean::primality::probabilistic::miller_rabin_primality_test;
+
+fn time<F: FnMut()>(iterations: u32, mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        f();
+    }
+    start.elapsed() / iterations
+}
+
+fn report(name: &str, baseline: Duration, montgomery: Duration) {
+    println!("{:<28} mod_exp {:>10.2?}   montgomery {:>10.2?}   speedup {:.2}x",
+             name, baseline, montgomery, baseline.as_secs_f64() / montgomery.as_secs_f64());
+}
+
+fn main() {
+    let n: u64 = u64::MAX - 58;
+    let ctx = Montgomery::<u64>::new(n);
+    let bases: Vec<u64> = (0..1_000).map(|i| 0x9e37_79b9_7f4a_7c15u64.wrapping_mul(i + 1) % n).collect();
+
+    let baseline = time(20, || for a in &bases {
+        black_box(mod_exp(black_box(*a), n - 1, n));
+    }) / bases.len() as u32;
+    let montgomery = time(20, || for a in &bases {
+        black_box(ctx.to_residue(ctx.pow(&ctx.to_form(black_box(*a)), &(n - 1))));
+    }) / bases.len() as u32;
+    report("u64 pow, 64-bit modulus", baseline, montgomery);
+
+    let n: u128 = u128::MAX - 158;
+    let ctx = Montgomery::<u128>::new(n);
+    let bases: Vec<u128> = bases.iter().map(|&a| (a as u128) << 60 | 12_345).collect();
+
+    let baseline = time(2, || for a in &bases {
+        black_box(mod_exp(black_box(*a), n - 1, n));
+    }) / bases.len() as u32;
+    let montgomery = time(2, || for a in &bases {
+        black_box(ctx.to_residue(ctx.pow(&ctx.to_form(black_box(*a)), &(n - 1))));
+    }) / bases.len() as u32;
+    report("u128 pow, 128-bit modulus", baseline, montgomery);
+
+    // Miller-Rabin itself runs on Montgomery contexts, shown for scale.
+    let mr = time(1_000, || {
+        black_box(miller_rabin_primality_test(black_box(u64::MAX - 58), 20));
+    });
+    println!("{:<28} {:>10.2?}", "Miller-Rabin, 20 rounds", mr);
+}
@@ -0,0 +1,188 @@
+// Classical arithmetic functions, for one n > 0 from its factorization and for all of [1, N]
+// from a linear sieve. The sieved versions return a vector v with v[i] = f(i) for 1 <= i <= N
+// and a zero at index 0, and the same types as the functions for one n.
+use crate::utils::gcd;
+use crate::factor::{factorize, Factorization};
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a / gcd(a, b) * b
+}
+
+// λ(p^e): p^(e - 1) (p - 1), except for 2^e with e >= 3 where the group is not cyclic
+fn carmichael_prime_power(p: u64, e: u32) -> u64 {
+    if p == 2 && e >= 3 {
+        1 << (e - 2)
+    } else {
+        p.pow(e - 1) * (p - 1)
+    }
+}
+
+// Euler's totient φ(n), the number of 1 <= k <= n coprime to n
+pub fn euler_phi(n: u64) -> u64 {
+    factorize(n).iter().map(|(&p, e)| p.pow(e - 1) * (p - 1)).product()
+}
+
+// Carmichael's λ(n), the exponent of the multiplicative group mod n
+pub fn carmichael_lambda(n: u64) -> u64 {
+    factorize(n).iter().fold(1, |l, (&p, e)| lcm(l, carmichael_prime_power(p, e)))
+}
+
+// Möbius μ(n): (-1)^ω(n) for square-free n, 0 otherwise
+pub fn mobius(n: u64) -> i8 {
+    let factorization = factorize(n);
+    if factorization.iter().any(|(_, e)| e > 1) {
+        0
+    } else {
+        liouville_of(&factorization)
+    }
+}
+
+// σ_k(n), the sum of d^k over the divisors d of n, which has to fit into u64
+pub fn sigma(n: u64, k: u32) -> u64 {
+    factorize(n).divisor_sum(k)
+}
+
+// τ(n) = σ_0(n), the number of divisors of n
+pub fn tau(n: u64) -> u64 {
+    factorize(n).divisor_count()
+}
+
+// Ω(n), the number of prime factors of n counted with multiplicity
+pub fn big_omega(n: u64) -> u32 {
+    factorize(n).iter().map(|(_, e)| e).sum()
+}
+
+// ω(n), the number of distinct prime factors of n
+pub fn omega(n: u64) -> u32 {
+    factorize(n).len() as u32
+}
+
+fn liouville_of(factorization: &Factorization<u64>) -> i8 {
+    let parity: u32 = factorization.iter().map(|(_, e)| e).sum();
+    if parity.is_multiple_of(2) { 1 } else { -1 }
+}
+
+// Liouville's λ(n) = (-1)^Ω(n)
+pub fn liouville(n: u64) -> i8 {
+    liouville_of(&factorize(n))
+}
+
+// von Mangoldt's Λ(n): ln p when n is a power of the prime p, 0 otherwise
+pub fn von_mangoldt(n: u64) -> f64 {
+    let factorization = factorize(n);
+    match factorization.len() {
+        1 => (*factorization.primes().next().expect("one prime") as f64).ln(),
+        _ => 0.0,
+    }
+}
+
+// For every 2 <= m <= N the smallest prime p of m, its exponent e and q = p^e, so that
+// m = q (m / q) with coprime parts. The linear sieve reaches each composite m exactly once,
+// as its smallest prime times m / p, which makes it O(N).
+struct SmallestPrimePowers {
+    prime: Vec<u32>,
+    exponent: Vec<u8>,
+    power: Vec<u32>,
+}
+
+impl SmallestPrimePowers {
+    fn new(n: u64) -> Self {
+        assert!(n <= u32::MAX as u64, "the sieved arithmetic functions go up to 2^32 - 1");
+        let n = n as usize;
+        let mut prime = vec![0u32; n + 1];
+        let mut exponent = vec![0u8; n + 1];
+        let mut power = vec![0u32; n + 1];
+        let mut primes: Vec<u32> = Vec::new();
+        for m in 2..=n {
+            if prime[m] == 0 {
+                (prime[m], exponent[m], power[m]) = (m as u32, 1, m as u32);
+                primes.push(m as u32);
+            }
+            for &p in &primes {
+                if p > prime[m] || m * p as usize > n {
+                    break;
+                }
+                let mp = m * p as usize;
+                prime[mp] = p;
+                (exponent[mp], power[mp]) = if p == prime[m] {
+                    (exponent[m] + 1, power[m] * p)
+                } else {
+                    (1, p)
+                };
+            }
+        }
+        SmallestPrimePowers{ prime, exponent, power }
+    }
+
+    fn len(&self) -> usize {
+        self.prime.len()
+    }
+
+    // (p, e, q) for m >= 2
+    fn at(&self, m: usize) -> (u64, u32, usize) {
+        (self.prime[m] as u64, self.exponent[m] as u32, self.power[m] as usize)
+    }
+}
+
+// f(i) for i in [1, n] from f(1) and f(m) = combine(f(q), f(m / q)) with q = p^e the prime
+// power of the smallest prime of m, where f(q) = at_prime_power(p, e, q)
+fn sieve_up_to<V, F, C>(n: u64, one: V, at_prime_power: F, combine: C) -> Vec<V>
+where
+    V: Clone + Default,
+    F: Fn(u64, u32, u64) -> V,
+    C: Fn(&V, &V) -> V,
+{
+    let table = SmallestPrimePowers::new(n);
+    let mut values = vec![V::default(); table.len()];
+    if table.len() > 1 {
+        values[1] = one;
+    }
+    for m in 2..table.len() {
+        let (p, e, q) = table.at(m);
+        values[m] = if q == m {
+            at_prime_power(p, e, q as u64)
+        } else {
+            combine(&values[q], &values[m / q])
+        };
+    }
+    values
+}
+
+pub fn euler_phi_up_to(n: u64) -> Vec<u64> {
+    sieve_up_to(n, 1, |p, _, q| q / p * (p - 1), |a, b| a * b)
+}
+
+pub fn carmichael_lambda_up_to(n: u64) -> Vec<u64> {
+    sieve_up_to(n, 1, |p, e, _| carmichael_prime_power(p, e), |&a, &b| lcm(a, b))
+}
+
+pub fn mobius_up_to(n: u64) -> Vec<i8> {
+    sieve_up_to(n, 1, |_, e, _| if e == 1 { -1 } else { 0 }, |a, b| a * b)
+}
+
+pub fn sigma_up_to(n: u64, k: u32) -> Vec<u64> {
+    // σ_k(p^e) = 1 + p^k + ... + (p^e)^k
+    let prime_power = |p: u64, e: u32, _| (0..=e).map(|i| p.pow(i * k)).sum();
+    sieve_up_to(n, 1, prime_power, |a, b| a * b)
+}
+
+pub fn tau_up_to(n: u64) -> Vec<u64> {
+    sieve_up_to(n, 1, |_, e, _| e as u64 + 1, |a, b| a * b)
+}
+
+pub fn big_omega_up_to(n: u64) -> Vec<u32> {
+    sieve_up_to(n, 0, |_, e, _| e, |a, b| a + b)
+}
+
+pub fn omega_up_to(n: u64) -> Vec<u32> {
+    sieve_up_to(n, 0, |_, _, _| 1, |a, b| a + b)
+}
+
+pub fn liouville_up_to(n: u64) -> Vec<i8> {
+    sieve_up_to(n, 1, |_, e, _| if e.is_multiple_of(2) { 1 } else { -1 }, |a, b| a * b)
+}
+
+pub fn von_mangoldt_up_to(n: u64) -> Vec<f64> {
+    // Only prime powers have a nonzero value, and 0 combined with anything stays 0.
+    sieve_up_to(n, 0.0, |p, _, _| (p as f64).ln(), |_, _| 0.0)
+}
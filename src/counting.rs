@@ -0,0 +1,343 @@
+// The prime-counting function by the Lagarias-Miller-Odlyzko method, following
+// Oliveira e Silva, "Computing π(x): the combinatorial method" (2006).
+//
+// With y ~ x^(1/3) and a = π(y), π(x) = φ(x, a) + a - 1 - P2(x, a) where φ(x, a) counts the
+// integers up to x free of the first a primes and P2(x, a) those with exactly two prime
+// factors above p_a. φ(x, a) is a sum over the leaves n <= y of μ(n) φ(x / n, C) for the
+// ordinary ones and over the special leaves, which a segmented sieve of [1, x / y) counts.
+use num::integer::Roots;
+
+use crate::sieve::{Sieve, primes_in};
+
+// x below this is counted with a plain sieve
+const SIEVE_BOUND: u64 = 1 << 20;
+
+// The first C primes are removed from each segment by a periodic pattern, see PhiTiny
+const C: usize = 6;
+const TINY_PRIMES: [u64; C] = [2, 3, 5, 7, 11, 13];
+const TINY_PRODUCT: u64 = 2 * 3 * 5 * 7 * 11 * 13;
+
+// Integers sieved per segment of [1, x / y)
+const SEGMENT_SIZE: u64 = 1 << 18;
+
+// φ(v, C), from a table of the residues mod 2·3·5·7·11·13 coprime to it
+struct PhiTiny {
+    // prefix[r]: the residues in [0, r] coprime to the product
+    prefix: Vec<u32>,
+    // two periods of the residues coprime to the product, to copy segments from
+    pattern: Vec<bool>,
+}
+
+impl PhiTiny {
+    fn new() -> Self {
+        let period: Vec<bool> = (0..TINY_PRODUCT).map(|r| TINY_PRIMES.iter().all(|&p| r % p != 0)).collect();
+        let prefix = period.iter().scan(0, |count, &coprime| {
+            *count += coprime as u32;
+            Some(*count)
+        }).collect();
+        let pattern = [period.clone(), period].concat();
+        PhiTiny{ prefix, pattern }
+    }
+
+    // Whether each integer of [low, high) is coprime to the product
+    fn coprime(&self, low: u64, high: u64) -> Vec<bool> {
+        let mut flags = Vec::with_capacity((high - low) as usize);
+        let mut n = low;
+        while n < high {
+            let r = (n % TINY_PRODUCT) as usize;
+            let len = (TINY_PRODUCT as usize).min((high - n) as usize);
+            flags.extend_from_slice(&self.pattern[r..r + len]);
+            n += len as u64;
+        }
+        flags
+    }
+
+    fn phi(&self, v: u64) -> i64 {
+        let per_period = *self.prefix.last().expect("the table is not empty") as u64;
+        ((v / TINY_PRODUCT) * per_period + self.prefix[(v % TINY_PRODUCT) as usize] as u64) as i64
+    }
+}
+
+// The prefix counts of the unsieved integers of a segment
+struct Fenwick {
+    tree: Vec<i32>,
+}
+
+impl Fenwick {
+    fn new(flags: &[bool]) -> Self {
+        let mut tree: Vec<i32> = flags.iter().map(|&flag| flag as i32).collect();
+        for i in 0..tree.len() {
+            let parent = i | (i + 1);
+            if parent < tree.len() {
+                tree[parent] += tree[i];
+            }
+        }
+        Fenwick{ tree }
+    }
+
+    fn remove(&mut self, mut i: usize) {
+        while i < self.tree.len() {
+            self.tree[i] -= 1;
+            i |= i + 1;
+        }
+    }
+
+    // The unsieved integers at indices up to and including i
+    fn count(&self, i: usize) -> i64 {
+        let mut sum = 0;
+        let mut i = i as isize;
+        while i >= 0 {
+            sum += self.tree[i as usize] as i64;
+            i = (i & (i + 1)) - 1;
+        }
+        sum
+    }
+}
+
+// Least prime factor, Möbius function and π, for 0..=y
+struct Tables {
+    primes: Vec<u64>,
+    lpf: Vec<u32>,
+    mu: Vec<i8>,
+    pi: Vec<u32>,
+}
+
+impl Tables {
+    fn new(y: u64) -> Self {
+        let y = y as usize;
+        let mut lpf = vec![0u32; y + 1];
+        let mut mu = vec![1i8; y + 1];
+        let mut pi = vec![0u32; y + 1];
+        // primes[0] is a placeholder so that primes[b] is p_b
+        let mut primes = vec![0u64];
+        for i in 2..=y {
+            if lpf[i] == 0 {
+                primes.push(i as u64);
+                for multiple in (i..=y).step_by(i) {
+                    if lpf[multiple] == 0 {
+                        lpf[multiple] = i as u32;
+                    }
+                    mu[multiple] = -mu[multiple];
+                }
+                for multiple in (i * i..=y).step_by(i * i) {
+                    mu[multiple] = 0;
+                }
+            }
+            pi[i] = primes.len() as u32 - 1;
+        }
+        if y >= 1 {
+            // 1 has no prime factor, which the leaf conditions read as larger than any prime
+            lpf[1] = u32::MAX;
+        }
+        Tables{ primes, lpf, mu, pi }
+    }
+}
+
+// The ordinary leaves: μ(n) φ(x / n, C) for the n <= y free of the first C primes
+fn ordinary_leaves(x: u64, y: u64, tables: &Tables, tiny: &PhiTiny) -> i64 {
+    let p_c = TINY_PRIMES[C - 1] as u32;
+    (1..=y).filter(|&n| tables.mu[n as usize] != 0 && tables.lpf[n as usize] > p_c)
+           .map(|n| tables.mu[n as usize] as i64 * tiny.phi(x / n))
+           .sum()
+}
+
+// The special leaves n = p_b m with m <= y < n and lpf(m) > p_b, each contributing
+// -μ(m) φ(x / n, b - 1), by a segmented sieve of [1, z) that removes p_b at step b.
+fn special_leaves(x: u64, y: u64, z: u64, tables: &Tables, tiny: &PhiTiny) -> i64 {
+    let Tables{ primes, lpf, mu, pi } = tables;
+    let pi_y = pi[y as usize] as usize;
+    let pi_sqrt_y = pi[y.sqrt() as usize] as usize;
+    let mut sum = 0;
+    // phi[b]: φ(low - 1, b - 1)
+    let mut phi = vec![0i64; pi_y + 1];
+    let mut low = 1;
+    while low < z {
+        let high = (low + SEGMENT_SIZE).min(z);
+        let mut flags = tiny.coprime(low, high);
+        let mut tree = Fenwick::new(&flags);
+        let mut unsieved = tree.count(flags.len() - 1);
+
+        for b in C + 1..pi_y {
+            let p = primes[b];
+            let mut max_m = (x / p / low).min(y);
+            if b >= pi_sqrt_y {
+                max_m = max_m.min(last_hard_leaf(x, y, p));
+            }
+            if p >= max_m {
+                // No leaf left for b, any larger b or any later segment
+                break;
+            }
+            if b < pi_sqrt_y {
+                let min_m = (x / p / high).max(y / p);
+                for m in (min_m + 1..=max_m).rev() {
+                    if mu[m as usize] != 0 && lpf[m as usize] as u64 > p {
+                        let v = x / (p * m);
+                        sum -= mu[m as usize] as i64 * (phi[b] + tree.count((v - low) as usize));
+                    }
+                }
+            } else {
+                // Here m <= y has no prime factor up to sqrt(y), so m is a prime.
+                let min_m = (x / p / high).max(y / p).max(p);
+                let mut l = pi[max_m as usize] as usize;
+                while primes[l] > min_m {
+                    let v = x / (p * primes[l]);
+                    sum += phi[b] + tree.count((v - low) as usize);
+                    l -= 1;
+                }
+            }
+            phi[b] += unsieved;
+
+            // The odd multiples of p in the segment
+            let mut multiple = low.div_ceil(p) * p;
+            if multiple % 2 == 0 {
+                multiple += p;
+            }
+            while multiple < high {
+                let i = (multiple - low) as usize;
+                if flags[i] {
+                    flags[i] = false;
+                    tree.remove(i);
+                    unsieved -= 1;
+                }
+                multiple += 2 * p;
+            }
+        }
+        low = high;
+    }
+    sum
+}
+
+// The largest m of a hard special leaf p m with p > sqrt(y): for larger m, x / (p m) <= y
+// and x / (p m) < p^2, which leaves the primes to count in φ(x / (p m), b - 1).
+fn last_hard_leaf(x: u64, y: u64, p: u64) -> u64 {
+    x / p / (y.min(p * p - 1) + 1)
+}
+
+// The easy special leaves p_b m, with m prime and v = x / (p_b m) not past the last hard leaf,
+// each contributing φ(v, b - 1) = 1 + max(0, π(v) - b + 1). The m with the same π(v) are summed at once.
+fn easy_leaves(x: u64, y: u64, tables: &Tables) -> i64 {
+    let Tables{ primes, pi, .. } = tables;
+    let pi_y = pi[y as usize] as usize;
+    let pi_sqrt_y = pi[y.sqrt() as usize] as usize;
+    let mut sum = 0;
+    for b in (C + 1).max(pi_sqrt_y)..pi_y {
+        let p = primes[b];
+        let lower = last_hard_leaf(x, y, p).max(y / p).max(p);
+        let mut l = pi_y;
+        while primes[l] > lower {
+            // π(x / (p m)) stays k while x / (p m) < p_(k + 1), and φ(x / (p m), b - 1)
+            // is 1 for all the x / (p m) < p_b.
+            let k = (pi[(x / (p * primes[l])) as usize] as usize).max(b - 1);
+            let bound = match primes.get(k + 1) {
+                Some(&next) => (x / (p * next)).max(lower),
+                None => lower,
+            };
+            let next_l = pi[bound as usize] as usize;
+            sum += (1 + (k as i64 - b as i64 + 1).max(0)) * (l - next_l) as i64;
+            l = next_l;
+        }
+    }
+    sum
+}
+
+// P2(x, a) = Σ (π(x / p) - π(p) + 1) over the primes y < p <= sqrt(x)
+fn p2(x: u64, y: u64, pi_y: u64) -> i64 {
+    let sqrt_x = x.sqrt();
+    if sqrt_x <= y {
+        return 0;
+    }
+    let large: Vec<u64> = primes_in(y + 1..sqrt_x + 1).collect();
+    // x / p increases as p decreases, so one pass of the primes up to x / y counts them all.
+    let mut counted = 0;
+    let mut primes = primes_in(0..x / y + 1).peekable();
+    let mut sum = 0;
+    for (i, &p) in large.iter().enumerate().rev() {
+        let target = x / p;
+        while primes.next_if(|&q| q <= target).is_some() {
+            counted += 1;
+        }
+        let k = pi_y + i as u64 + 1;
+        sum += counted - (k as i64 - 1);
+    }
+    sum
+}
+
+// π(x), the number of primes up to and including x
+pub fn prime_pi(x: u64) -> u64 {
+    if x < SIEVE_BOUND {
+        return Sieve::new(x).count() as u64;
+    }
+    // y = α x^(1/3), with α growing like log(x)^2 to trade the special leaves against the sieve
+    let ln = (x as f64).ln();
+    let alpha = (ln * ln / 100.0).max(1.0);
+    let y = ((x.cbrt() as f64 * alpha) as u64).min(x.sqrt());
+    let z = x / y + 1;
+    let tables = Tables::new(y);
+    let tiny = PhiTiny::new();
+    let pi_y = tables.pi[y as usize] as u64;
+
+    let phi = ordinary_leaves(x, y, &tables, &tiny) + easy_leaves(x, y, &tables) + special_leaves(x, y, z, &tables, &tiny);
+    (phi + pi_y as i64 - 1 - p2(x, y, pi_y)) as u64
+}
+
+// The logarithmic integral li(x) for x > 1, by Ramanujan's series
+pub fn li(x: f64) -> f64 {
+    const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+    let ln = x.ln();
+    let mut sum = 0.0;
+    let mut term = 1.0;
+    let mut inner = 0.0;
+    for n in 1..200 {
+        term *= -ln / n as f64 / 2.0;
+        if (n - 1) % 2 == 0 {
+            inner += 1.0 / n as f64;
+        }
+        let next = -term * inner;
+        sum += next;
+        if next.abs() < 1e-17 * sum.abs() {
+            break;
+        }
+    }
+    EULER_GAMMA + ln.ln() + x.sqrt() * 2.0 * sum
+}
+
+// The n-th prime, counting from p_1 = 2: π(x) at the root of li(x) = n, then a sieve to p_n
+pub fn nth_prime(n: u64) -> u64 {
+    assert!(n >= 1, "primes are counted from 1");
+    if n < 100_000 {
+        let ln = (n as f64).ln().max(1.0);
+        let bound = (n as f64 * (ln + ln.ln() + 3.0)) as u64 + 10;
+        return primes_in(0..bound).nth(n as usize - 1).expect("p_n < n (ln n + ln ln n) for n >= 6");
+    }
+
+    // Bisection for li(x) = n, with ln n < x / n < 2 ln n for the n here
+    let target = n as f64;
+    let (mut lo, mut hi) = (target * target.ln(), 2.0 * target * target.ln());
+    while hi - lo > 1.0 {
+        let mid = (lo + hi) / 2.0;
+        if li(mid) < target {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    let x = lo as u64;
+    let count = prime_pi(x);
+    if count < n {
+        return primes_in(x + 1..u64::MAX).nth((n - count - 1) as usize).expect("there is always a next prime");
+    }
+
+    // Walk down in windows until the n-th prime is in one
+    let mut above = count - n;
+    let mut hi = x + 1;
+    loop {
+        let width = (4 * (above + 1) * (x as f64).ln() as u64).max(1 << 12);
+        let lo = hi.saturating_sub(width);
+        let window: Vec<u64> = primes_in(lo..hi).collect();
+        if (window.len() as u64) > above {
+            return window[window.len() - 1 - above as usize];
+        }
+        above -= window.len() as u64;
+        hi = lo;
+    }
+}
@@ -0,0 +1,128 @@
+// Elliptic curves y^2 = x^3 + a x + b over Z/nZ in affine coordinates.
+// n need not be prime: when a denominator turns out not to be invertible,
+// the arithmetic stops and returns its gcd with n, a factor of n.
+use crate::utils::{Int, add_mod, sub_mod, mod_inverse, sqrt_mod, gcd};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Curve<T> {
+    pub a: T,
+    pub b: T,
+    pub n: T,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Point<T> {
+    Infinity,
+    Affine(T, T),
+}
+
+fn int<T: Int>(n: u64) -> T {
+    T::from_u64(n).expect("constant fits into T")
+}
+
+impl<T: Int> Curve<T> {
+    pub fn new(a: T, b: T, n: T) -> Self {
+        Curve{ a: a.mod_floor(&n), b: b.mod_floor(&n), n }
+    }
+
+    // 4 a^3 + 27 b^2 mod n, the curve is nonsingular modulo every prime factor of n
+    // iff this is coprime to n
+    pub fn discriminant(&self) -> T {
+        let n = &self.n;
+        let a_cubed = self.a.mul_mod(&self.a, n).mul_mod(&self.a, n);
+        let b_squared = self.b.mul_mod(&self.b, n);
+        add_mod(&int::<T>(4).mul_mod(&a_cubed, n), &int::<T>(27).mod_floor(n).mul_mod(&b_squared, n), n)
+    }
+
+    pub fn is_nonsingular(&self) -> bool {
+        gcd(self.discriminant(), self.n.clone()).is_one()
+    }
+
+    // x^3 + a x + b mod n
+    pub fn rhs(&self, x: &T) -> T {
+        let n = &self.n;
+        let x_squared_plus_a = add_mod(&x.mul_mod(x, n), &self.a, n);
+        add_mod(&x_squared_plus_a.mul_mod(x, n), &self.b, n)
+    }
+
+    pub fn contains(&self, p: &Point<T>) -> bool {
+        match p {
+            Point::Infinity => true,
+            Point::Affine(x, y) => *x < self.n && *y < self.n && y.mul_mod(y, &self.n) == self.rhs(x),
+        }
+    }
+
+    // A point with the given x coordinate, None if x^3 + a x + b is not a square.
+    // Only meaningful for prime n.
+    pub fn lift_x(&self, x: T) -> Option<Point<T>> {
+        let x = x.mod_floor(&self.n);
+        let y = sqrt_mod(self.rhs(&x), self.n.clone())?;
+        let point = Point::Affine(x, y);
+        if self.contains(&point) {
+            Some(point)
+        } else {
+            None
+        }
+    }
+
+    pub fn negate(&self, p: &Point<T>) -> Point<T> {
+        match p {
+            Point::Infinity => Point::Infinity,
+            Point::Affine(x, y) => Point::Affine(x.clone(), sub_mod(&T::zero(), y, &self.n)),
+        }
+    }
+
+    pub fn add(&self, p: &Point<T>, q: &Point<T>) -> Result<Point<T>, T> {
+        let n = &self.n;
+        let (x1, y1, x2, y2) = match (p, q) {
+            (Point::Infinity, _) => return Ok(q.clone()),
+            (_, Point::Infinity) => return Ok(p.clone()),
+            (Point::Affine(x1, y1), Point::Affine(x2, y2)) => (x1, y1, x2, y2),
+        };
+        if x1 == x2 {
+            let sum = add_mod(y1, y2, n);
+            if sum.is_zero() {
+                return Ok(Point::Infinity);
+            }
+            if y1 == y2 {
+                return self.double(p);
+            }
+            // y1 = +-y2 modulo every prime factor, but neither modulo n
+            return Err(gcd(sum, n.clone()));
+        }
+        let slope = sub_mod(y2, y1, n).mul_mod(&mod_inverse(&sub_mod(x2, x1, n), n)?, n);
+        Ok(self.chord(&slope, x1, y1, x2))
+    }
+
+    pub fn double(&self, p: &Point<T>) -> Result<Point<T>, T> {
+        let n = &self.n;
+        let (x, y) = match p {
+            Point::Affine(x, y) if !y.is_zero() => (x, y),
+            _ => return Ok(Point::Infinity),
+        };
+        let three_x_squared = int::<T>(3).mod_floor(n).mul_mod(&x.mul_mod(x, n), n);
+        let numerator = add_mod(&three_x_squared, &self.a, n);
+        let slope = numerator.mul_mod(&mod_inverse(&add_mod(y, y, n), n)?, n);
+        Ok(self.chord(&slope, x, y, x))
+    }
+
+    // The third intersection of the line through (x1, y1) with the given slope, reflected
+    fn chord(&self, slope: &T, x1: &T, y1: &T, x2: &T) -> Point<T> {
+        let n = &self.n;
+        let x3 = sub_mod(&sub_mod(&slope.mul_mod(slope, n), x1, n), x2, n);
+        let y3 = sub_mod(&slope.mul_mod(&sub_mod(x1, &x3, n), n), y1, n);
+        Point::Affine(x3, y3)
+    }
+
+    // k p by double-and-add from the top bit
+    pub fn mul(&self, p: &Point<T>, k: &T) -> Result<Point<T>, T> {
+        let mut result = Point::Infinity;
+        for i in (0..k.bits()).rev() {
+            result = self.double(&result)?;
+            if k.bit(i) {
+                result = self.add(&result, p)?;
+            }
+        }
+        Ok(result)
+    }
+}
@@ -0,0 +1,258 @@
+// Integer factorization: trial division by the sieved small primes, then Pollard p - 1,
+// Williams p + 1, a bounded run of Brent's rho, ECM and for large enough numbers SIQS
+// on what is left until every part passes the primality test.
+use std::collections::btree_map::{self, BTreeMap};
+use std::fmt::{self, Display};
+
+use rand::{Rng, thread_rng};
+
+use crate::utils::{Int, exact_sqrt};
+use crate::primality::deterministic::is_prime;
+use crate::primality::probabilistic::bpsw_primality_test;
+use crate::sieve::primes_up_to;
+
+pub mod rho;
+pub mod pm1;
+pub mod pp1;
+pub mod ecm;
+pub mod siqs;
+pub mod squfof;
+pub mod fermat;
+pub mod divisors;
+pub use rho::{brent_rho, pollard_rho};
+pub use pm1::pollard_pm1;
+pub use pp1::{williams_pp1, williams_pp1_seed};
+pub use ecm::{ecm, ecm_curve, EcmParameters};
+pub use siqs::{siqs, siqs_with_rng};
+pub use squfof::squfof;
+pub use fermat::{fermat, lehman, hart_olf};
+pub use divisors::{Divisors, SortedDivisors};
+
+const TRIAL_DIVISION_BOUND: u64 = 1 << 12;
+
+// Bounds of the quick p - 1 attempt made before rho
+const PM1_B1: u64 = 1 << 10;
+const PM1_B2: u64 = 1 << 15;
+
+// Bounds and number of seeds of the p + 1 attempt after p - 1. A seed costs about twice
+// as much as p - 1, and half of the seeds give p - 1 over again.
+const PP1_B1: u64 = 1 << 10;
+const PP1_B2: u64 = 1 << 15;
+const PP1_SEEDS: usize = 2;
+
+// Rho steps before moving on to ECM, which is faster past factors of about 2^32
+const RHO_ITERATIONS: u64 = 1 << 16;
+
+// Up to this size SQUFOF is quicker than rho, which sets up a Montgomery context for each c.
+// Past it, rho beats SQUFOF, Lehman and Hart's method by 2x or more at every size up to 64 bits.
+const SQUFOF_BITS: u32 = 28;
+
+// From about 40 digits on, SIQS takes over once ECM has looked for factors of 2/9 of the digits.
+const SIQS_BITS: u64 = 130;
+
+// The prime factorization of a positive integer, primes in increasing order
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Factorization<T> {
+    factors: BTreeMap<T, u32>,
+}
+
+impl<T: Int> Factorization<T> {
+    // The factorization of 1
+    pub fn new() -> Self {
+        Factorization{ factors: BTreeMap::new() }
+    }
+
+    // Multiplies in prime^exponent
+    pub fn insert(&mut self, prime: T, exponent: u32) {
+        if exponent > 0 {
+            *self.factors.entry(prime).or_insert(0) += exponent;
+        }
+    }
+
+    pub fn exponent(&self, prime: &T) -> u32 {
+        self.factors.get(prime).copied().unwrap_or(0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&T, u32)> + '_ {
+        self.factors.iter().map(|(p, &e)| (p, e))
+    }
+
+    pub fn primes(&self) -> impl Iterator<Item = &T> + '_ {
+        self.factors.keys()
+    }
+
+    // The number of distinct primes
+    pub fn len(&self) -> usize {
+        self.factors.len()
+    }
+
+    // Whether this is the factorization of 1
+    pub fn is_empty(&self) -> bool {
+        self.factors.is_empty()
+    }
+}
+
+impl<T: Int> Default for Factorization<T> {
+    fn default() -> Self {
+        Factorization::new()
+    }
+}
+
+impl<T> IntoIterator for Factorization<T> {
+    type Item = (T, u32);
+    type IntoIter = btree_map::IntoIter<T, u32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.factors.into_iter()
+    }
+}
+
+impl<T: Display> Display for Factorization<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.factors.is_empty() {
+            return write!(f, "1");
+        }
+        let terms: Vec<String> = self.factors.iter().map(|(p, &e)| match e {
+            1 => p.to_string(),
+            _ => format!("{}^{}", p, e),
+        }).collect();
+        write!(f, "{}", terms.join(" * "))
+    }
+}
+
+fn int<T: Int>(n: u64) -> T {
+    T::from_u64(n).expect("constant fits into T")
+}
+
+// The deterministic test below 2^64, BPSW above
+fn is_probable_prime<T: Int>(n: &T) -> bool {
+    match n.to_u64() {
+        Some(n) => is_prime(n),
+        None => !bpsw_primality_test(n.clone()).is_composite(),
+    }
+}
+
+// (r, k) with n = r^k for the largest such k, for n without prime factors up to the trial division bound
+fn perfect_power<T: Int>(n: &T) -> Option<(T, u32)> {
+    // r > TRIAL_DIVISION_BOUND = 2^12 leaves k < bits / 12.
+    let max_k = (n.bits() / 12) as u32;
+    for k in primes_up_to(max_k as u64) {
+        let r = n.nth_root(k as u32);
+        if num::pow(r.clone(), k as usize) == *n {
+            let (root, j) = perfect_power(&r).unwrap_or((r, 1));
+            return Some((root, j * k as u32));
+        }
+    }
+    None
+}
+
+// A nontrivial factor of n < 2^64, None for 1 and primes: SQUFOF backed by Lehman's method
+// for small n, Brent's rho above
+pub fn small_factor(n: u64) -> Option<u64> {
+    if n < 4 || is_prime(n) {
+        return None;
+    }
+    if n.is_multiple_of(2) {
+        return Some(2);
+    }
+    if let Some(r) = exact_sqrt(&n) {
+        return Some(r);
+    }
+    if 64 - n.leading_zeros() <= SQUFOF_BITS {
+        return squfof(n).or_else(|| lehman(n));
+    }
+    (1..).find_map(|c| brent_rho(&n, &c, u64::MAX))
+}
+
+// A nontrivial factor of an odd composite n that is not a perfect power
+fn find_factor<T: Int, R: Rng + ?Sized>(n: &T, rng: &mut R) -> T {
+    if n.bits() <= SQUFOF_BITS as u64 {
+        if let Some(d) = n.to_u64().and_then(small_factor) {
+            return int(d);
+        }
+    }
+    if let Some(d) = pollard_pm1(n, PM1_B1, PM1_B2) {
+        return d;
+    }
+    if let Some(d) = williams_pp1(n, PP1_B1, PP1_B2, PP1_SEEDS) {
+        return d;
+    }
+    if let Some(d) = brent_rho(n, &T::one(), RHO_ITERATIONS) {
+        return d;
+    }
+    // ECM wants n without factors 2 and 3, which trial division has taken out.
+    let use_siqs = n.bits() >= SIQS_BITS;
+    let ecm_digits = if use_siqs { (n.bits() * 2 / 30).max(15) as u32 } else { u32::MAX };
+    let mut levels = EcmParameters::levels();
+    for (digits, params) in levels.by_ref() {
+        if let Some(d) = ecm(n, &params, rng) {
+            return d;
+        }
+        if digits >= ecm_digits {
+            break;
+        }
+    }
+    if use_siqs {
+        if let Some(d) = siqs_with_rng(n, rng) {
+            return d;
+        }
+    }
+    if let Some(d) = levels.find_map(|(_, params)| ecm(n, &params, rng)) {
+        return d;
+    }
+    pollard_rho(n)
+}
+
+// The prime factorization of n > 0
+pub fn factorize<T: Int>(n: T) -> Factorization<T> {
+    factorize_with_rng(n, &mut thread_rng())
+}
+
+// The prime factorization of n > 0, with the random choices of ECM and SIQS drawn from rng
+pub fn factorize_with_rng<T: Int, R: Rng + ?Sized>(n: T, rng: &mut R) -> Factorization<T> {
+    assert!(!n.is_zero(), "0 has no factorization");
+    let mut factorization = Factorization::new();
+    let mut n = n;
+    for p in primes_up_to(TRIAL_DIVISION_BOUND) {
+        let p: T = int(p);
+        if p.clone() * p.clone() > n {
+            break;
+        }
+        let mut exponent = 0;
+        loop {
+            let (q, r) = n.div_rem(&p);
+            if !r.is_zero() {
+                break;
+            }
+            n = q;
+            exponent += 1;
+        }
+        factorization.insert(p, exponent);
+    }
+    if n.is_one() {
+        return factorization;
+    }
+    // Without prime factors up to the bound, n below its square is a prime.
+    let bound: T = int(TRIAL_DIVISION_BOUND);
+    if n < bound.clone() * bound {
+        factorization.insert(n, 1);
+        return factorization;
+    }
+
+    let mut stack = vec![(n, 1)];
+    while let Some((m, exponent)) = stack.pop() {
+        if is_probable_prime(&m) {
+            factorization.insert(m, exponent);
+            continue;
+        }
+        if let Some((r, k)) = perfect_power(&m) {
+            stack.push((r, exponent * k));
+            continue;
+        }
+        let d = find_factor(&m, rng);
+        let cofactor = m / d.clone();
+        stack.push((d, exponent));
+        stack.push((cofactor, exponent));
+    }
+    factorization
+}
@@ -0,0 +1,139 @@
+// Divisors of a factored n, generated from the exponents without factoring anything again.
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::utils::Int;
+use super::Factorization;
+
+// The divisors b_0^a_0 b_1^a_1 ... with 0 <= a_i <= e_i for pairwise coprime bases b_i, in no
+// particular order. An odometer on the a_i, with the products of the digits from each
+// position upwards kept so that a step costs one multiplication on average.
+#[derive(Clone, Debug)]
+pub struct Divisors<T> {
+    // powers[i][a] = b_i^a
+    powers: Vec<Vec<T>>,
+    exponents: Vec<u32>,
+    // suffix[i] = product of b_j^a_j for j >= i, suffix[len] = 1
+    suffix: Vec<T>,
+    done: bool,
+}
+
+impl<T: Int> Divisors<T> {
+    fn new(bases: Vec<(T, u32)>) -> Self {
+        let powers: Vec<Vec<T>> = bases.into_iter().map(|(b, e)| {
+            let mut row = vec![T::one()];
+            for _ in 0..e {
+                let next = row.last().expect("row starts with 1").clone() * b.clone();
+                row.push(next);
+            }
+            row
+        }).collect();
+        let len = powers.len();
+        Divisors{ powers, exponents: vec![0; len], suffix: vec![T::one(); len + 1], done: false }
+    }
+}
+
+impl<T: Int> Iterator for Divisors<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.done {
+            return None;
+        }
+        let divisor = self.suffix[0].clone();
+        // The lowest digit that can still go up, all below it back to 0
+        match (0..self.powers.len()).find(|&i| (self.exponents[i] as usize) + 1 < self.powers[i].len()) {
+            Some(i) => {
+                self.exponents[i] += 1;
+                self.suffix[i] = self.powers[i][self.exponents[i] as usize].clone() * self.suffix[i + 1].clone();
+                for j in (0..i).rev() {
+                    self.exponents[j] = 0;
+                    self.suffix[j] = self.suffix[i].clone();
+                }
+            }
+            None => self.done = true,
+        }
+        Some(divisor)
+    }
+}
+
+// The divisors in increasing order. Every divisor other than 1 has one parent, itself with
+// the exponent of its largest prime with a nonzero exponent lowered by 1, and the heap holds
+// the children of the divisors returned so far.
+#[derive(Clone, Debug)]
+pub struct SortedDivisors<T> {
+    factors: Vec<(T, u32)>,
+    // (divisor, exponents, index of the last nonzero exponent or 0)
+    heap: BinaryHeap<Reverse<(T, Vec<u32>, usize)>>,
+}
+
+impl<T: Int> Iterator for SortedDivisors<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let Reverse((divisor, exponents, last)) = self.heap.pop()?;
+        for i in last..self.factors.len() {
+            let (p, e) = &self.factors[i];
+            // Past the last nonzero exponent only the step from 0 to 1
+            if exponents[i] < *e && (i == last || exponents[i] == 0) {
+                let mut child = exponents.clone();
+                child[i] += 1;
+                self.heap.push(Reverse((divisor.clone() * p.clone(), child, i)));
+            }
+        }
+        Some(divisor)
+    }
+}
+
+impl<T: Int> Factorization<T> {
+    // The number itself
+    pub fn value(&self) -> T {
+        self.factors.iter().fold(T::one(), |n, (p, &e)| n * num::pow(p.clone(), e as usize))
+    }
+
+    // All divisors, lazily and in no particular order
+    pub fn divisors(&self) -> Divisors<T> {
+        Divisors::new(self.iter().map(|(p, e)| (p.clone(), e)).collect())
+    }
+
+    // All divisors, lazily in increasing order
+    pub fn sorted_divisors(&self) -> SortedDivisors<T> {
+        let factors: Vec<(T, u32)> = self.iter().map(|(p, e)| (p.clone(), e)).collect();
+        let heap = BinaryHeap::from([Reverse((T::one(), vec![0; factors.len()], 0))]);
+        SortedDivisors{ factors, heap }
+    }
+
+    // The divisors d <= bound in increasing order
+    pub fn divisors_up_to(&self, bound: &T) -> impl Iterator<Item = T> {
+        let bound = bound.clone();
+        self.sorted_divisors().take_while(move |d| *d <= bound)
+    }
+
+    // The divisors d with gcd(d, n / d) = 1, which take each prime power p^e of n whole or not at all
+    pub fn unitary_divisors(&self) -> Divisors<T> {
+        Divisors::new(self.iter().map(|(p, e)| (num::pow(p.clone(), e as usize), 1)).collect())
+    }
+
+    // The divisors without square factors, products of distinct primes of n
+    pub fn squarefree_divisors(&self) -> Divisors<T> {
+        Divisors::new(self.primes().map(|p| (p.clone(), 1)).collect())
+    }
+
+    // The number of divisors, the product of e + 1
+    pub fn divisor_count(&self) -> u64 {
+        self.iter().map(|(_, e)| e as u64 + 1).product()
+    }
+
+    // The sum of d^k over the divisors d, the product of 1 + p^k + ... + p^ek.
+    // For k = 0 the number of divisors. The sum has to fit into T.
+    pub fn divisor_sum(&self, k: u32) -> T {
+        self.iter().fold(T::one(), |sum, (p, e)| {
+            let p_k = num::pow(p.clone(), k as usize);
+            let mut term = T::one();
+            for _ in 0..e {
+                term = term * p_k.clone() + T::one();
+            }
+            sum * term
+        })
+    }
+}
@@ -0,0 +1,232 @@
+// Lenstra's elliptic curve method on Montgomery curves B y^2 = x^3 + A x^2 + x, in projective
+// (X : Z) coordinates where only x is tracked. Curves come from Suyama's parametrization,
+// whose group orders are divisible by 12. Stage 1 multiplies a point by every prime power
+// up to B1; stage 2 catches one more prime q up to B2 with baby steps j Q and giant steps m D Q.
+use rand::Rng;
+
+use crate::utils::{Int, gcd, add_mod, sub_mod, mod_inverse};
+use crate::montgomery::ModularContext;
+use crate::sieve::primes_in;
+
+// Bounds and curve counts, by the number of digits of the factor looked for. The counts are those
+// of GMP-ECM, whose stage 2 goes much further, so a factor of that size may take more curves here.
+const LEVELS: [(u32, u64, usize); 7] = [
+    (15, 2_000, 25),
+    (20, 11_000, 90),
+    (25, 50_000, 300),
+    (30, 250_000, 700),
+    (35, 1_000_000, 1_800),
+    (40, 3_000_000, 5_100),
+    (45, 11_000_000, 10_600),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EcmParameters {
+    pub b1: u64,
+    pub b2: u64,
+    pub curves: usize,
+}
+
+impl EcmParameters {
+    // The parameters for factors of about this many digits
+    pub fn for_digits(digits: u32) -> Self {
+        let &(_, b1, curves) = LEVELS.iter()
+                                     .find(|&&(d, _, _)| d >= digits)
+                                     .unwrap_or(&LEVELS[LEVELS.len() - 1]);
+        EcmParameters{ b1, b2: 100 * b1, curves }
+    }
+
+    // The digits and parameters of every level in turn, for factors of unknown size
+    pub fn levels() -> impl Iterator<Item = (u32, EcmParameters)> {
+        LEVELS.iter().map(|&(digits, _, _)| (digits, EcmParameters::for_digits(digits)))
+    }
+}
+
+// A point (X : Z) in the internal form of the context
+#[derive(Clone, Debug)]
+struct Point<T> {
+    x: T,
+    z: T,
+}
+
+// The curve arithmetic mod n, with a24 = (A + 2) / 4
+struct Curve<'a, T: Int> {
+    ctx: &'a T::Context,
+    n: T,
+    a24: T,
+}
+
+impl<T: Int> Curve<'_, T> {
+    fn add(&self, a: &T, b: &T) -> T {
+        add_mod(a, b, &self.n)
+    }
+
+    fn sub(&self, a: &T, b: &T) -> T {
+        sub_mod(a, b, &self.n)
+    }
+
+    fn double(&self, p: &Point<T>) -> Point<T> {
+        let ctx = self.ctx;
+        let sum = ctx.square(&self.add(&p.x, &p.z));
+        let difference = ctx.square(&self.sub(&p.x, &p.z));
+        let t = self.sub(&sum, &difference);
+        Point{
+            x: ctx.mul(&sum, &difference),
+            z: ctx.mul(&t, &self.add(&difference, &ctx.mul(&self.a24, &t))),
+        }
+    }
+
+    // p + q from p, q and p - q
+    fn add_points(&self, p: &Point<T>, q: &Point<T>, difference: &Point<T>) -> Point<T> {
+        let ctx = self.ctx;
+        let u = ctx.mul(&self.sub(&p.x, &p.z), &self.add(&q.x, &q.z));
+        let v = ctx.mul(&self.add(&p.x, &p.z), &self.sub(&q.x, &q.z));
+        Point{
+            x: ctx.mul(&difference.z, &ctx.square(&self.add(&u, &v))),
+            z: ctx.mul(&difference.x, &ctx.square(&self.sub(&u, &v))),
+        }
+    }
+
+    // k p by the Montgomery ladder, for k >= 1
+    fn mul(&self, p: &Point<T>, k: u64) -> Point<T> {
+        let (mut r0, mut r1) = (p.clone(), self.double(p));
+        for i in (0..63 - k.leading_zeros()).rev() {
+            if (k >> i) & 1 == 1 {
+                r0 = self.add_points(&r1, &r0, p);
+                r1 = self.double(&r1);
+            } else {
+                r1 = self.add_points(&r1, &r0, p);
+                r0 = self.double(&r0);
+            }
+        }
+        r0
+    }
+}
+
+// The outcome of a gcd with n: a proper factor, nothing, or all of n
+fn split<T: Int>(d: T, n: &T) -> Result<Option<T>, ()> {
+    if d.is_one() {
+        Ok(None)
+    } else if d == *n {
+        Err(())
+    } else {
+        Ok(Some(d))
+    }
+}
+
+// Stage 2 with giant steps of D Q, and a baby step j Q for each j < D / 2 coprime to D,
+// so that every prime q in (b1, b2] is m D + j or m D - j. The x-coordinates of m D Q and
+// j Q agree mod p when q Q is the identity mod p.
+fn stage_two<T: Int>(curve: &Curve<T>, q: &Point<T>, b1: u64, b2: u64) -> T {
+    let ctx = curve.ctx;
+    let d: u64 = if b2 - b1 < 1 << 22 { 210 } else { 2_310 };
+    let half = d / 2;
+
+    // baby[j] = x(j Q) = X / Z for odd j < D / 2, kept only when gcd(j, D) = 1, so that
+    // each prime takes one product. A Z that is not invertible is returned for the gcd.
+    let double = curve.double(q);
+    let mut baby: Vec<Option<T>> = vec![None; half as usize];
+    let (mut previous, mut current) = (q.clone(), q.clone());
+    for j in (1..half).step_by(2) {
+        if gcd(j, d) == 1 {
+            let z = ctx.to_residue(current.z.clone());
+            match mod_inverse(&z, &curve.n) {
+                Ok(inverse) => baby[j as usize] = Some(ctx.mul(&current.x, &ctx.to_form(inverse))),
+                Err(_) => return current.z,
+            }
+        }
+        let next = if j == 1 { curve.add_points(&double, q, q) } else { curve.add_points(&current, &double, &previous) };
+        previous = std::mem::replace(&mut current, next);
+    }
+
+    let step = curve.mul(q, d);
+    let first = (b1 / d).max(1);
+    let mut previous = if first == 1 { q.clone() } else { curve.mul(q, (first - 1) * d) };
+    let mut giant = curve.mul(q, first * d);
+    // With m = 1, the difference D Q - 0 Q is not a point; m D Q - (m - 1) D Q stays D Q instead.
+    let mut lagging = first == 1;
+    let mut primes = primes_in(b1 + 1..b2 + 1).peekable();
+    let mut product = ctx.one();
+    let mut m = first;
+    while primes.peek().is_some() {
+        let mut used = vec![false; half as usize];
+        while let Some(p) = primes.next_if(|&p| p <= m * d + half) {
+            // Primes below D / 2 are left out when b1 is that small.
+            let j = p.abs_diff(m * d) as usize;
+            if j >= half as usize || used[j] {
+                continue;
+            }
+            used[j] = true;
+            if let Some(x) = &baby[j] {
+                let term = curve.sub(&giant.x, &ctx.mul(x, &giant.z));
+                product = ctx.mul(&product, &term);
+            }
+        }
+        let next = if lagging { curve.double(&giant) } else { curve.add_points(&giant, &step, &previous) };
+        lagging = false;
+        previous = std::mem::replace(&mut giant, next);
+        m += 1;
+    }
+    product
+}
+
+// One curve with Suyama's parameter sigma > 5: a proper factor of n, or None when the curve
+// does not split n. n is odd, without factors 2 or 3, and not a perfect power.
+pub fn ecm_curve<T: Int>(n: &T, b1: u64, b2: u64, sigma: &T) -> Option<T> {
+    // Machine words in Montgomery form are many times faster than big integers.
+    let sigma = sigma.mod_floor(n);
+    if let (Some(n), Some(sigma)) = (n.to_u64(), sigma.to_u64()) {
+        return curve_factor(&n, b1, b2, &sigma).map(|d| T::from_u64(d).expect("a factor of n fits into T"));
+    }
+    if let (Some(n), Some(sigma)) = (n.to_u128(), sigma.to_u128()) {
+        return curve_factor(&n, b1, b2, &sigma).map(|d| T::from_u128(d).expect("a factor of n fits into T"));
+    }
+    curve_factor(n, b1, b2, &sigma)
+}
+
+fn curve_factor<T: Int>(n: &T, b1: u64, b2: u64, sigma: &T) -> Option<T> {
+    let ctx = T::Context::new(n.clone());
+    let int = |k: u64| T::from_u64(k).expect("constant fits into T");
+    let mul = |a: &T, b: &T| a.mul_mod(b, n);
+
+    // u = sigma^2 - 5, v = 4 sigma, x = u^3, z = v^3, (A + 2) / 4 = (v - u)^3 (3 u + v) / (16 u^3 v)
+    let u = sub_mod(&mul(sigma, sigma), &int(5), n);
+    let v = mul(&int(4), sigma);
+    let u3 = mul(&mul(&u, &u), &u);
+    let v3 = mul(&mul(&v, &v), &v);
+    let w = sub_mod(&v, &u, n);
+    let numerator = mul(&mul(&mul(&w, &w), &w), &add_mod(&mul(&int(3), &u), &v, n));
+    let denominator = mul(&mul(&int(16), &u3), &v);
+    let a24 = match mod_inverse(&denominator, n) {
+        Ok(inverse) => mul(&numerator, &inverse),
+        Err(d) => return split(d, n).ok().flatten(),
+    };
+    let curve = Curve{ ctx: &ctx, n: n.clone(), a24: ctx.to_form(a24) };
+    let mut q = Point{ x: ctx.to_form(u3), z: ctx.to_form(v3) };
+
+    for p in primes_in(2..b1 + 1) {
+        let mut power = p;
+        while power <= b1 / p {
+            power *= p;
+        }
+        q = curve.mul(&q, power);
+    }
+    match split(gcd(ctx.to_residue(q.z.clone()), n.clone()), n) {
+        Ok(Some(d)) => return Some(d),
+        Err(()) => return None,
+        Ok(None) => {}
+    }
+    if b2 <= b1 {
+        return None;
+    }
+    split(gcd(ctx.to_residue(stage_two(&curve, &q, b1, b2)), n.clone()), n).ok().flatten()
+}
+
+// ECM with the given bounds on up to params.curves random curves
+pub fn ecm<T: Int, R: Rng + ?Sized>(n: &T, params: &EcmParameters, rng: &mut R) -> Option<T> {
+    let low = T::from_u64(6).expect("constant fits into T");
+    (0..params.curves).find_map(|_| {
+        let sigma = T::random_range(rng, &low, n);
+        ecm_curve(n, params.b1, params.b2, &sigma)
+    })
+}
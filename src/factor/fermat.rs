@@ -0,0 +1,100 @@
+// Factoring by a difference of squares a^2 - b^2 = kN: Fermat's method for k = 1,
+// Lehman's for every k up to N^(1/3), and Hart's one line factoring for squares mod N.
+// The squares run past u64, so they are taken in u128.
+use crate::utils::{gcd, exact_sqrt, integer_root};
+use crate::sieve::primes_up_to;
+
+const HART_MULTIPLIER: u128 = 480;
+
+// ceil(sqrt(n)), from the floating point root corrected by one either way
+fn ceil_sqrt(n: u128) -> u128 {
+    let mut root = (n as f64).sqrt() as u128;
+    while root * root > n {
+        root -= 1;
+    }
+    while root * root < n {
+        root += 1;
+    }
+    root
+}
+
+// The factor a - b of an odd n = a^2 - b^2 with a from ceil(sqrt(n)) on, quick when n has a
+// factor close to sqrt(n). None for primes and after max_iterations values of a.
+pub fn fermat(n: u64, max_iterations: u64) -> Option<u64> {
+    if n < 4 {
+        return None;
+    }
+    if n.is_multiple_of(2) {
+        return Some(2);
+    }
+    let n = n as u128;
+    let start = ceil_sqrt(n);
+    let mut b2 = start * start - n;
+    for a in (start..).take(max_iterations as usize) {
+        if let Some(b) = exact_sqrt(&b2) {
+            // a - b = 1 is the trivial n = 1 * n.
+            return (a - b > 1).then_some((a - b) as u64);
+        }
+        b2 += 2 * a + 1;
+    }
+    None
+}
+
+// Lehman's method: trial division up to N^(1/3), then a^2 - 4kN = b^2 for k up to N^(1/3)
+// and sqrt(4kN) <= a <= sqrt(4kN) + N^(1/6) / (4 sqrt(k)), which finds a factor of every
+// composite. None exactly for primes.
+pub fn lehman(n: u64) -> Option<u64> {
+    if n < 4 {
+        return None;
+    }
+    // The trial division bound is below 2 for n < 8.
+    if n.is_multiple_of(2) {
+        return Some(2);
+    }
+    let cube_root = integer_root(&n, 3);
+    if let Some(p) = primes_up_to(cube_root).into_iter().find(|&p| n.is_multiple_of(p)) {
+        return (p < n).then_some(p);
+    }
+    let sixth_root = (n as f64).powf(1.0 / 6.0);
+    let n = n as u128;
+    for k in 1..=cube_root as u128 {
+        let four_kn = 4 * k * n;
+        let low = ceil_sqrt(four_kn);
+        // One more for the rounding of the floating point bound
+        let high = four_kn.isqrt() + (sixth_root / (4.0 * (k as f64).sqrt())) as u128 + 1;
+        for a in low..=high {
+            if let Some(b) = exact_sqrt(&(a * a - four_kn)) {
+                let d = gcd(a + b, n);
+                if d != 1 && d != n {
+                    return Some(d as u64);
+                }
+            }
+        }
+    }
+    None
+}
+
+// Hart's one line factoring: s = ceil(sqrt(iN)) for i = 1, 2, ... until s^2 mod N is a square t^2,
+// when gcd(s - t, N) is a factor. Heuristically O(N^(1/3)) steps; None after max_iterations.
+pub fn hart_olf(n: u64, max_iterations: u64) -> Option<u64> {
+    if n < 4 {
+        return None;
+    }
+    if n.is_multiple_of(2) {
+        return Some(2);
+    }
+    if let Some(r) = exact_sqrt(&n) {
+        return Some(r);
+    }
+    let n = n as u128;
+    for i in 1..=max_iterations as u128 {
+        let s = ceil_sqrt(HART_MULTIPLIER * i * n);
+        if let Some(t) = exact_sqrt(&(s * s % n)) {
+            let d = gcd(s - t, n);
+            if d != 1 && d != n {
+                return Some(d as u64);
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,78 @@
+use crate::utils::{Int, gcd, sub_mod};
+use crate::montgomery::ModularContext;
+use crate::sieve::primes_in;
+
+// Stage 2 steps multiplied together between two gcds
+const BATCH: usize = 256;
+
+// 2^E mod n, with E the product of the largest powers of the primes up to b1 not past b1.
+// The exponent is applied in chunks that fit into every T; with `careful`, one prime power
+// at a time, stopping as soon as the gcd is not 1.
+fn stage_one<T: Int, C: ModularContext<T>>(ctx: &C, b1: u64, careful: bool) -> (T, T) {
+    let n = ctx.modulus();
+    let mut a = ctx.to_form(T::one() + T::one());
+    let mut chunk: u64 = 1;
+    for p in primes_in(2..b1 + 1) {
+        let mut q = p;
+        while q <= b1 / p {
+            q *= p;
+        }
+        if careful || chunk * q > u32::MAX as u64 {
+            a = ctx.pow(&a, &T::from_u64(chunk).expect("chunks fit into u32"));
+            chunk = 1;
+            if careful {
+                let d = gcd(sub_mod(&a, &ctx.one(), &n), n.clone());
+                if !d.is_one() {
+                    return (a, d);
+                }
+            }
+        }
+        chunk *= q;
+    }
+    a = ctx.pow(&a, &T::from_u64(chunk).expect("chunks fit into u32"));
+    let d = gcd(sub_mod(&a, &ctx.one(), &n), n.clone());
+    (a, d)
+}
+
+// Pollard p - 1 on an odd n: finds a prime factor p when p - 1 is b1-smooth apart from
+// at most one prime up to b2. Stage 2 steps through the primes q in (b1, b2] with a^q
+// updated by the powers of a for the prime gaps. None when this does not split n.
+pub fn pollard_pm1<T: Int>(n: &T, b1: u64, b2: u64) -> Option<T> {
+    let ctx = T::Context::new(n.clone());
+    let (mut a, mut d) = stage_one(&ctx, b1, false);
+    if d == *n {
+        // Every factor came out at once; separate them prime by prime.
+        (a, d) = stage_one(&ctx, b1, true);
+    }
+    if !d.is_one() {
+        return if d == *n { None } else { Some(d) };
+    }
+
+    let mut primes = primes_in(b1 + 1..b2 + 1);
+    let first = primes.next()?;
+    let mut x = ctx.pow(&a, &T::from_u64(first).expect("b2 fits into T"));
+    let mut product = sub_mod(&x, &ctx.one(), n);
+    let mut previous = first;
+    // gaps[i] = a^(2i)
+    let square = ctx.square(&a);
+    let mut gaps = vec![ctx.one()];
+    for (i, q) in primes.enumerate() {
+        let gap = ((q - previous) / 2) as usize;
+        while gaps.len() <= gap {
+            let next = ctx.mul(gaps.last().expect("gaps starts with a^0"), &square);
+            gaps.push(next);
+        }
+        x = ctx.mul(&x, &gaps[gap]);
+        previous = q;
+        product = ctx.mul(&product, &sub_mod(&x, &ctx.one(), n));
+        if (i + 1) % BATCH == 0 && !gcd(product.clone(), n.clone()).is_one() {
+            break;
+        }
+    }
+    d = gcd(product, n.clone());
+    if d.is_one() || d == *n {
+        None
+    } else {
+        Some(d)
+    }
+}
@@ -0,0 +1,131 @@
+// Williams' p + 1: for a seed P_0 with D = P_0^2 - 4 not a square mod p, the roots of
+// x^2 - P_0 x + 1 lie in GF(p^2) and have an order dividing p + 1, so V_E(P_0) = 2 mod p once
+// p + 1 divides E. When D is a square mod p the same steps amount to p - 1, which is why
+// several seeds with different D are tried.
+use crate::utils::{Int, gcd, sub_mod};
+use crate::montgomery::ModularContext;
+use crate::lucas::lucas_v;
+use crate::sieve::primes_in;
+
+// Stage 2 steps multiplied together between two gcds
+const BATCH: usize = 256;
+
+// Seeds in the order they are tried. The square-free parts of D = P_0^2 - 4 are
+// 5, 3, 21, 2, 15, 77, 6, 13 and differ, so each seed is a new chance at p + 1.
+const SEEDS: [u64; 8] = [3, 4, 5, 6, 8, 9, 10, 11];
+
+// V_E(x) mod n, with E the product of the largest powers of the primes up to b1 not past b1,
+// and the gcd of V_E(x) - 2 with n. Chunks of E fit into every T; with `careful`, one prime
+// power at a time, stopping as soon as the gcd is not 1.
+fn stage_one<T: Int, C: ModularContext<T>>(ctx: &C, x: &T, b1: u64, careful: bool) -> (T, T) {
+    let n = ctx.modulus();
+    let two = ctx.to_form(T::one() + T::one());
+    let mut x = x.clone();
+    let mut chunk: u64 = 1;
+    for p in primes_in(2..b1 + 1) {
+        let mut q = p;
+        while q <= b1 / p {
+            q *= p;
+        }
+        if careful || chunk * q > u32::MAX as u64 {
+            x = lucas_v(ctx, &x, &T::from_u64(chunk).expect("chunks fit into u32"));
+            chunk = 1;
+            if careful {
+                let d = gcd(sub_mod(&x, &two, &n), n.clone());
+                if !d.is_one() {
+                    return (x, d);
+                }
+            }
+        }
+        chunk *= q;
+    }
+    x = lucas_v(ctx, &x, &T::from_u64(chunk).expect("chunks fit into u32"));
+    let d = gcd(sub_mod(&x, &two, &n), n.clone());
+    (x, d)
+}
+
+// Stage 2 for the primes q in (b1, b2], each written as m D + j or m D - j with j < D / 2
+// coprime to D. V_mD - V_j = (a^mD - a^j)(1 - a^-(mD + j)) for a root a, so it vanishes mod p
+// when either of m D - j and m D + j is a multiple of the order of a. The giant steps follow
+// V_(m+1)D = V_mD V_D - V_(m-1)D and the baby steps V_(j+2) = V_j V_2 - V_(j-2).
+fn stage_two<T: Int, C: ModularContext<T>>(ctx: &C, x: &T, b1: u64, b2: u64) -> T {
+    let n = ctx.modulus();
+    let d: u64 = if b2 - b1 > 1 << 20 { 2310 } else { 210 };
+    let half = (d / 2) as usize;
+    let int = |k: u64| T::from_u64(k).expect("constant fits into T");
+
+    // baby[j] = V_j for odd j < D / 2, V_-1 = V_1 to start the recurrence
+    let v2 = lucas_v(ctx, x, &int(2));
+    let mut baby = vec![T::zero(); half];
+    let (mut previous, mut current) = (x.clone(), x.clone());
+    for j in (1..half).step_by(2) {
+        baby[j] = current.clone();
+        let next = sub_mod(&ctx.mul(&current, &v2), &previous, &n);
+        previous = current;
+        current = next;
+    }
+
+    let v_d = lucas_v(ctx, x, &int(d));
+    let mut m = (b1 + 1) / d;
+    let mut giant = lucas_v(ctx, x, &int(m * d));
+    let mut giant_next = lucas_v(ctx, x, &int((m + 1) * d));
+    let mut done = vec![false; half];
+    let mut product = ctx.one();
+    for (i, q) in primes_in(b1 + 1..b2 + 1).enumerate() {
+        let target = (q + d / 2) / d;
+        while m < target {
+            let next = sub_mod(&ctx.mul(&giant_next, &v_d), &giant, &n);
+            giant = std::mem::replace(&mut giant_next, next);
+            m += 1;
+            done.iter_mut().for_each(|seen| *seen = false);
+        }
+        let j = q.abs_diff(m * d) as usize;
+        // m D - j and m D + j share one factor, and j = 0 or an even j only come with q | D.
+        if j.is_multiple_of(2) || done[j] {
+            continue;
+        }
+        done[j] = true;
+        product = ctx.mul(&product, &sub_mod(&giant, &baby[j], &n));
+        if (i + 1) % BATCH == 0 && !gcd(product.clone(), n.clone()).is_one() {
+            break;
+        }
+    }
+    gcd(product, n)
+}
+
+// p + 1 with one seed P_0 > 2: a proper factor of an odd n, or None when this seed does not
+// split n. Stage 1 covers p + 1 that are b1-smooth, stage 2 one more prime up to b2.
+pub fn williams_pp1_seed<T: Int>(n: &T, b1: u64, b2: u64, seed: u64) -> Option<T> {
+    // Machine words in Montgomery form are many times faster than big integers.
+    if let Some(n) = n.to_u64() {
+        return seed_factor(&n, b1, b2, seed).map(|d| T::from_u64(d).expect("a factor of n fits into T"));
+    }
+    if let Some(n) = n.to_u128() {
+        return seed_factor(&n, b1, b2, seed).map(|d| T::from_u128(d).expect("a factor of n fits into T"));
+    }
+    seed_factor(n, b1, b2, seed)
+}
+
+fn seed_factor<T: Int>(n: &T, b1: u64, b2: u64, seed: u64) -> Option<T> {
+    let ctx = T::Context::new(n.clone());
+    let x = ctx.to_form(T::from_u64(seed).expect("seed fits into T"));
+    let (mut x_e, mut d) = stage_one(&ctx, &x, b1, false);
+    if d == *n {
+        // Every factor came out at once; separate them prime by prime.
+        (x_e, d) = stage_one(&ctx, &x, b1, true);
+    }
+    if d.is_one() && b2 > b1 {
+        d = stage_two(&ctx, &x_e, b1, b2);
+    }
+    if d.is_one() || d == *n {
+        None
+    } else {
+        Some(d)
+    }
+}
+
+// Williams' p + 1 on an odd n with up to `seeds` different seeds, each with stage 1 to b1
+// and stage 2 to b2. None when none of them splits n.
+pub fn williams_pp1<T: Int>(n: &T, b1: u64, b2: u64, seeds: usize) -> Option<T> {
+    SEEDS.iter().take(seeds).find_map(|&seed| williams_pp1_seed(n, b1, b2, seed))
+}
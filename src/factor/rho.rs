@@ -0,0 +1,69 @@
+use crate::utils::{Int, gcd, add_mod, sub_mod};
+use crate::montgomery::ModularContext;
+
+// Differences multiplied together between two gcds
+const BATCH: u64 = 128;
+
+// Brent's variant of Pollard rho on x -> x^2 + c mod n, for odd n. The gcd with n is taken
+// once per batch of differences, and a batch that overshoots to n is replayed one step at a time.
+// None when the walk closes up without splitting n or takes more than max_iterations steps.
+pub fn brent_rho<T: Int>(n: &T, c: &T, max_iterations: u64) -> Option<T> {
+    let ctx = T::Context::new(n.clone());
+    let c = ctx.to_form(c.clone());
+    let f = |x: &T| add_mod(&ctx.square(x), &c, n);
+    let mut y = ctx.to_form(T::one() + T::one());
+    let mut x = y.clone();
+    let mut saved = y.clone();
+    let mut product = ctx.one();
+    let mut d = T::one();
+    let mut r = 1;
+    let mut iterations = 0;
+    while d.is_one() {
+        x = y.clone();
+        for _ in 0..r {
+            y = f(&y);
+        }
+        let mut k = 0;
+        while k < r && d.is_one() {
+            saved = y.clone();
+            for _ in 0..BATCH.min(r - k) {
+                y = f(&y);
+                product = ctx.mul(&product, &sub_mod(&x, &y, n));
+            }
+            d = gcd(product.clone(), n.clone());
+            k += BATCH;
+        }
+        iterations += 2 * r;
+        r *= 2;
+        if iterations > max_iterations && d.is_one() {
+            return None;
+        }
+    }
+    if d == *n {
+        // Replay the last batch from its start
+        loop {
+            saved = f(&saved);
+            d = gcd(sub_mod(&x, &saved, n), n.clone());
+            if !d.is_one() {
+                break;
+            }
+        }
+    }
+    if d == *n {
+        None
+    } else {
+        Some(d)
+    }
+}
+
+// A nontrivial factor of an odd composite n that is not a perfect power,
+// trying the maps x^2 + c for c = 1, 2, ... until one splits n
+pub fn pollard_rho<T: Int>(n: &T) -> T {
+    let mut c = T::one();
+    loop {
+        if let Some(d) = brent_rho(n, &c, u64::MAX) {
+            return d;
+        }
+        c = c + T::one();
+    }
+}
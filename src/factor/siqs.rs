@@ -0,0 +1,481 @@
+// The self-initializing quadratic sieve. Each polynomial g(x) = a x^2 + 2 b x + c with
+// b^2 - c a = kN gives relations (a x + b)^2 = a g(x) mod N wherever g(x) is smooth over the
+// factor base, the primes p with (kN / p) = 1. Here a is a product of s factor base primes,
+// and the 2^(s - 1) square roots b of kN mod a are visited in Gray code order, so that a new
+// polynomial moves the sieve roots by one addition per prime. Relations with one large prime
+// left over are kept until a second one with the same prime turns up. A dependency mod 2
+// among the exponent vectors gives X^2 = Y^2 mod N, and gcd(X - Y, N) a factor half the time.
+use std::collections::{HashMap, HashSet};
+
+use num::{BigInt, Integer, One, Signed, ToPrimitive, Zero};
+use rand::{Rng, thread_rng};
+
+use crate::utils::{Int, jacobi, sqrt_mod, mod_inverse, mul_mod};
+use crate::sieve::primes;
+
+// (digits of kN, factor base primes, sieve interval [-M, M))
+const PARAMETERS: [(u32, usize, usize); 14] = [
+    (20, 100, 1 << 13),
+    (25, 150, 1 << 14),
+    (30, 250, 1 << 15),
+    (35, 400, 1 << 15),
+    (40, 650, 1 << 15),
+    (45, 1_000, 1 << 15),
+    (50, 1_400, 1 << 16),
+    (55, 2_000, 1 << 16),
+    (60, 2_800, 1 << 16),
+    (65, 3_800, 1 << 16),
+    (70, 5_000, 3 << 15),
+    (75, 6_500, 3 << 15),
+    (80, 8_500, 1 << 17),
+    (90, 14_000, 1 << 17),
+];
+
+// Primes below this are left out of the sieve; their expected logarithms are made up by the threshold.
+const SMALL_PRIME_BOUND: u32 = 256;
+
+// The large prime of a partial relation is below this multiple of the largest factor base prime.
+const LARGE_PRIME_MULTIPLIER: u64 = 128;
+
+// Relations beyond the number of columns, each one more likely dependency
+const EXTRA_RELATIONS: usize = 24;
+
+// The candidate multipliers k, odd and square-free
+const MULTIPLIERS: [u32; 26] = [
+    1, 3, 5, 7, 11, 13, 15, 17, 19, 21, 23, 29, 31, 33, 35, 37, 39, 41, 43, 47, 51, 53, 55, 57, 59, 61,
+];
+
+// A factor base prime with a square root of kN mod p, zero when p divides kN, and its scaled logarithm
+struct Prime {
+    p: u32,
+    sqrt: u32,
+    log: u8,
+}
+
+// y^2 = the product of the primes in `columns` times large^2, mod N.
+// Column 0 stands for -1 and column i + 1 for the i-th factor base prime.
+struct Relation {
+    y: BigInt,
+    columns: Vec<usize>,
+    large: BigInt,
+}
+
+fn parameters(digits: u32) -> (usize, usize) {
+    let &(_, primes, half_width) = PARAMETERS.iter()
+                                             .find(|&&(d, _, _)| d >= digits)
+                                             .unwrap_or(&PARAMETERS[PARAMETERS.len() - 1]);
+    (primes, half_width)
+}
+
+fn mod_small(n: &BigInt, p: u32) -> u32 {
+    n.mod_floor(&BigInt::from(p)).to_u32().expect("a residue mod p fits into u32")
+}
+
+// The Knuth-Schroeppel multiplier: k whose kN has many small primes as quadratic residues,
+// weighed against the larger sieve values
+fn multiplier(n: &BigInt) -> u32 {
+    let small_primes: Vec<u32> = primes().skip(1).take(300).map(|p| p as u32).collect();
+    let score = |k: u32| {
+        let kn = n * k;
+        let mut score = -0.5 * (k as f64).ln();
+        score += match mod_small(&kn, 8) {
+            1 => 2.0,
+            5 => 1.0,
+            _ => 0.5,
+        } * 2f64.ln();
+        for &p in &small_primes {
+            let contribution = (p as f64).ln() / (p as f64 - 1.0);
+            if k.is_multiple_of(p) {
+                score += contribution;
+            } else if jacobi(mod_small(&kn, p), p) == 1 {
+                score += 2.0 * contribution;
+            }
+        }
+        score
+    };
+    MULTIPLIERS.iter().copied()
+               .map(|k| (k, score(k)))
+               .fold((1, f64::MIN), |best, (k, s)| if s > best.1 { (k, s) } else { best })
+               .0
+}
+
+// The factor base for kN: 2, the primes dividing k and those with (kN / p) = 1.
+// Err with a prime factor of n when one turns up.
+fn factor_base(n: &BigInt, kn: &BigInt, size: usize) -> Result<Vec<Prime>, BigInt> {
+    let mut base = Vec::with_capacity(size);
+    for p in primes().map(|p| p as u32) {
+        if base.len() == size {
+            break;
+        }
+        let residue = mod_small(kn, p);
+        if residue == 0 {
+            if mod_small(n, p) == 0 {
+                return Err(BigInt::from(p));
+            }
+        } else if p != 2 && jacobi(residue, p) != 1 {
+            continue;
+        }
+        let sqrt = if p == 2 { residue } else { sqrt_mod(residue, p).expect("kN is a residue mod p") };
+        base.push(Prime{ p, sqrt, log: 0 });
+    }
+    Ok(base)
+}
+
+// The primes of a, as factor base indices, chosen so that a is close to target: s - 1 at
+// random from those of about the right size, then the best fit for the rest.
+fn choose_a<R: Rng + ?Sized>(base: &[Prime], log_target: f64, attempt: usize, rng: &mut R) -> Vec<usize> {
+    let usable: Vec<usize> = (0..base.len()).filter(|&i| base[i].p > SMALL_PRIME_BOUND && base[i].sqrt != 0).collect();
+    let log_p = |i: usize| (base[i].p as f64).ln();
+    let largest = log_p(usable[usable.len() - 1]);
+    let mut s = (log_target / 2000f64.ln()).round().max(1.0) as usize;
+    // Primes past the factor base would leave a short of the target.
+    while log_target / (s as f64) > largest - 1.0 {
+        s += 1;
+    }
+    s = s.min(usable.len());
+    // The pool of primes closest to the ideal size widens as a values run out.
+    let ideal = log_target / s as f64;
+    let mut pool = usable.clone();
+    pool.sort_by(|&i, &j| (log_p(i) - ideal).abs().total_cmp(&(log_p(j) - ideal).abs()));
+    pool.truncate((3 * s + 8 + attempt / 4).min(pool.len()));
+
+    if s == 1 {
+        return vec![pool[rng.gen_range(0..pool.len())]];
+    }
+    let mut chosen: Vec<usize> = Vec::with_capacity(s);
+    while chosen.len() + 1 < s {
+        let i = pool[rng.gen_range(0..pool.len())];
+        if !chosen.contains(&i) {
+            chosen.push(i);
+        }
+    }
+    let rest = log_target - chosen.iter().map(|&i| log_p(i)).sum::<f64>();
+    let last = usable.iter()
+                     .copied()
+                     .filter(|i| !chosen.contains(i))
+                     .min_by(|&i, &j| (log_p(i) - rest).abs().total_cmp(&(log_p(j) - rest).abs()))
+                     .expect("there are more usable primes than primes of a");
+    chosen.push(last);
+    chosen.sort_unstable();
+    chosen
+}
+
+// Relations from sieving, full ones and pairs of partial ones with a common large prime,
+// until there are enough for the columns
+struct Sieving<'a, R: Rng + ?Sized> {
+    n: &'a BigInt,
+    kn: BigInt,
+    base: Vec<Prime>,
+    half_width: usize,
+    start: u8,
+    large_prime_bound: u64,
+    used: HashSet<BigInt>,
+    full: Vec<Relation>,
+    partial: HashMap<u64, Relation>,
+    rng: &'a mut R,
+}
+
+impl<R: Rng + ?Sized> Sieving<'_, R> {
+    // Sieves the 2^(s - 1) polynomials of one more a
+    fn next_a(&mut self) {
+        let log_target = (self.kn.bits() as f64 * 2f64.ln() + 2f64.ln()) / 2.0 - (self.half_width as f64).ln();
+        let mut attempt = 0;
+        let (q, a) = loop {
+            let q = choose_a(&self.base, log_target, attempt, self.rng);
+            let a: BigInt = q.iter().map(|&i| BigInt::from(self.base[i].p)).product();
+            if self.used.insert(a.clone()) {
+                break (q, a);
+            }
+            attempt += 1;
+        };
+
+        // b = B_0 + ... + B_(s-1) with B_l = 0 mod the other primes of a, B_l^2 = kN mod q_l
+        let big_b: Vec<BigInt> = q.iter().map(|&i| {
+            let (p, sqrt) = (self.base[i].p as u64, self.base[i].sqrt as u64);
+            let rest = &a / p;
+            let inverse = mod_inverse(&(mod_small(&rest, p as u32) as u64), &p).expect("the primes of a are distinct");
+            let mut gamma = mul_mod(sqrt, inverse, p);
+            if gamma > p / 2 {
+                gamma = p - gamma;
+            }
+            rest * gamma
+        }).collect();
+        let mut b: BigInt = big_b.iter().sum();
+
+        // The roots of g mod each sieved prime, as offsets into the interval, and
+        // their shifts 2 B_l / a mod p
+        let len = self.base.len();
+        let m = self.half_width as u64;
+        let mut roots = vec![(0u32, 0u32); len];
+        let mut shifts = vec![vec![0u32; len]; q.len()];
+        let mut sieved = vec![false; len];
+        for (i, prime) in self.base.iter().enumerate() {
+            if prime.p <= SMALL_PRIME_BOUND || prime.sqrt == 0 || q.contains(&i) {
+                continue;
+            }
+            sieved[i] = true;
+            let p = prime.p as u64;
+            let a_inverse = mod_inverse(&(mod_small(&a, prime.p) as u64), &p).expect("p does not divide a");
+            let b_p = mod_small(&b, prime.p) as u64;
+            let t = prime.sqrt as u64;
+            let root = |r: u64| ((mul_mod((r + p - b_p) % p, a_inverse, p) + m) % p) as u32;
+            roots[i] = (root(t), root(p - t));
+            for (l, big_b) in big_b.iter().enumerate() {
+                shifts[l][i] = mul_mod(2 * mod_small(big_b, prime.p) as u64, a_inverse, p) as u32;
+            }
+        }
+
+        for index in 0..1usize << (q.len() - 1) {
+            if index > 0 {
+                // Flip B_(l + 1) in Gray code order
+                let l = index.trailing_zeros() as usize + 1;
+                let plus = (index ^ (index >> 1)) & (1 << (l - 1)) == 0;
+                let shift = &shifts[l];
+                if plus {
+                    b += &big_b[l] * 2;
+                } else {
+                    b -= &big_b[l] * 2;
+                }
+                for i in 0..len {
+                    if sieved[i] {
+                        let p = self.base[i].p;
+                        let s = if plus { p - shift[i] } else { shift[i] };
+                        let (r1, r2) = roots[i];
+                        let reduce = |r: u32| if r >= p { r - p } else { r };
+                        roots[i] = (reduce(r1 + s), reduce(r2 + s));
+                    }
+                }
+            }
+            let c = (&b * &b - &self.kn) / &a;
+            self.sieve(&a, &b, &c, &q, &roots, &sieved);
+        }
+    }
+
+    fn sieve(&mut self, a: &BigInt, b: &BigInt, c: &BigInt, q: &[usize], roots: &[(u32, u32)], sieved: &[bool]) {
+        let len = 2 * self.half_width;
+        let mut logs = vec![self.start; len];
+        for (i, prime) in self.base.iter().enumerate() {
+            if !sieved[i] {
+                continue;
+            }
+            let p = prime.p as usize;
+            let (r1, r2) = roots[i];
+            for root in [r1 as usize, r2 as usize] {
+                for j in (root..len).step_by(p) {
+                    logs[j] = logs[j].wrapping_add(prime.log);
+                }
+            }
+        }
+        // The sums start at 128 less the threshold, so the candidates have the top bit set.
+        for (k, chunk) in logs.chunks_exact(8).enumerate() {
+            if u64::from_le_bytes(chunk.try_into().expect("chunks of 8")) & 0x8080_8080_8080_8080 == 0 {
+                continue;
+            }
+            for (l, &log) in chunk.iter().enumerate() {
+                if log & 0x80 != 0 {
+                    self.check(8 * k + l, a, b, c, q, roots, sieved);
+                }
+            }
+        }
+    }
+
+    // Trial divides g(x) at offset j by the factor base, and keeps the relation if smooth enough
+    #[allow(clippy::too_many_arguments)]
+    fn check(&mut self, j: usize, a: &BigInt, b: &BigInt, c: &BigInt, q: &[usize], roots: &[(u32, u32)], sieved: &[bool]) {
+        let x = BigInt::from(j as i64 - self.half_width as i64);
+        let mut value: BigInt = (a * &x + b * 2) * &x + c;
+        let mut columns: Vec<usize> = q.iter().map(|&i| i + 1).collect();
+        if value.is_negative() {
+            columns.push(0);
+            value = -value;
+        }
+        if value.is_zero() {
+            return;
+        }
+        for (i, prime) in self.base.iter().enumerate() {
+            let p = prime.p;
+            if sieved[i] {
+                let offset = (j % p as usize) as u32;
+                if offset != roots[i].0 && offset != roots[i].1 {
+                    continue;
+                }
+            }
+            let divisor = BigInt::from(p);
+            loop {
+                let (quotient, remainder) = value.div_rem(&divisor);
+                if !remainder.is_zero() {
+                    break;
+                }
+                value = quotient;
+                columns.push(i + 1);
+            }
+        }
+        let y = (a * x + b).mod_floor(self.n);
+        if value.is_one() {
+            self.full.push(Relation{ y, columns, large: BigInt::one() });
+            return;
+        }
+        let Some(large) = value.to_u64().filter(|&l| l < self.large_prime_bound) else { return };
+        match self.partial.remove(&large) {
+            Some(other) => {
+                columns.extend(other.columns);
+                let y = (y * other.y).mod_floor(self.n);
+                self.full.push(Relation{ y, columns, large: BigInt::from(large) });
+            }
+            None => {
+                self.partial.insert(large, Relation{ y, columns, large: BigInt::one() });
+            }
+        }
+    }
+}
+
+// The odd parity rows among the relations that sum to zero, after dropping relations
+// with a column no other one has, by Gaussian elimination on bit rows with their history
+fn dependencies(relations: &[Relation], columns: usize) -> Vec<Vec<usize>> {
+    let parity = |r: &Relation| {
+        let mut odd = vec![false; columns];
+        for &c in &r.columns {
+            odd[c] = !odd[c];
+        }
+        odd
+    };
+    let rows: Vec<Vec<bool>> = relations.iter().map(parity).collect();
+    let mut active: Vec<usize> = (0..relations.len()).collect();
+    loop {
+        let mut weight = vec![0usize; columns];
+        for &r in &active {
+            for (c, &odd) in rows[r].iter().enumerate() {
+                weight[c] += odd as usize;
+            }
+        }
+        let before = active.len();
+        active.retain(|&r| rows[r].iter().enumerate().all(|(c, &odd)| !odd || weight[c] != 1));
+        if active.len() == before {
+            break;
+        }
+    }
+
+    let words = (columns + active.len()).div_ceil(64);
+    let mut matrix: Vec<Vec<u64>> = active.iter().enumerate().map(|(k, &r)| {
+        let mut row = vec![0u64; words];
+        for (c, &odd) in rows[r].iter().enumerate() {
+            if odd {
+                row[c / 64] |= 1 << (c % 64);
+            }
+        }
+        let history = columns + k;
+        row[history / 64] |= 1 << (history % 64);
+        row
+    }).collect();
+    let mut pivots = 0;
+    for c in 0..columns {
+        let bit = |row: &Vec<u64>| row[c / 64] >> (c % 64) & 1 == 1;
+        let Some(pivot) = (pivots..matrix.len()).find(|&r| bit(&matrix[r])) else { continue };
+        matrix.swap(pivots, pivot);
+        let (done, rest) = matrix.split_at_mut(pivots + 1);
+        let pivot_row = &done[pivots];
+        for row in rest.iter_mut().filter(|row| bit(row)) {
+            for (word, &p) in row.iter_mut().zip(pivot_row) {
+                *word ^= p;
+            }
+        }
+        pivots += 1;
+    }
+    matrix[pivots..].iter().map(|row| {
+        (0..active.len()).filter(|&k| {
+            let h = columns + k;
+            row[h / 64] >> (h % 64) & 1 == 1
+        }).map(|k| active[k]).collect()
+    }).collect()
+}
+
+// A proper factor of n from a dependency, when X and Y are not equal up to sign
+fn square_root(n: &BigInt, base: &[Prime], relations: &[Relation], dependency: &[usize]) -> Option<BigInt> {
+    let mut exponents = vec![0u32; base.len() + 1];
+    let mut x = BigInt::one();
+    let mut y = BigInt::one();
+    for &r in dependency {
+        x = (x * &relations[r].y).mod_floor(n);
+        y = (y * &relations[r].large).mod_floor(n);
+        for &c in &relations[r].columns {
+            exponents[c] += 1;
+        }
+    }
+    for (i, prime) in base.iter().enumerate() {
+        let e = exponents[i + 1];
+        debug_assert!(e.is_multiple_of(2), "a dependency has even exponents");
+        y = (y * BigInt::from(prime.p).modpow(&BigInt::from(e / 2), n)).mod_floor(n);
+    }
+    let d = (x - y).gcd(n);
+    if d.is_one() || d == *n {
+        None
+    } else {
+        Some(d)
+    }
+}
+
+fn siqs_bigint<R: Rng + ?Sized>(n: &BigInt, rng: &mut R) -> Option<BigInt> {
+    let k = multiplier(n);
+    let kn = n * k;
+    let digits = (kn.bits() as f64 * 2f64.log10()).ceil() as u32;
+    let (size, half_width) = parameters(digits);
+    let mut base = match factor_base(n, &kn, size) {
+        Ok(base) => base,
+        Err(p) => return Some(p),
+    };
+    let largest = base[base.len() - 1].p as u64;
+    let large_prime_bound = largest * LARGE_PRIME_MULTIPLIER;
+    // log2 of the largest |g(x)|, about M sqrt(kN / 2), less the large primes and what the primes
+    // not sieved contribute on average, 2 log p / (p - 1) for each
+    let log_g = (half_width as f64).log2() + (kn.bits() as f64 - 1.0) / 2.0;
+    let unsieved: f64 = base.iter()
+                            .filter(|prime| prime.p <= SMALL_PRIME_BOUND && prime.sqrt != 0)
+                            .map(|prime| 2.0 * (prime.p as f64).log2() / (prime.p as f64 - 1.0))
+                            .sum();
+    let threshold = (log_g - (large_prime_bound as f64).log2() - unsieved).max(1.0);
+    // Logarithms to a base that keeps the threshold within 7 bits
+    let scale = (120.0 / threshold).min(1.0);
+    for prime in &mut base {
+        prime.log = ((prime.p as f64).log2() * scale).round() as u8;
+    }
+    let start = 128 - (threshold * scale).round() as u8;
+    let mut sieve = Sieving{
+        n,
+        kn,
+        base,
+        half_width,
+        start,
+        large_prime_bound,
+        used: HashSet::new(),
+        full: Vec::new(),
+        partial: HashMap::new(),
+        rng,
+    };
+
+    let columns = sieve.base.len() + 1;
+    let mut wanted = columns + EXTRA_RELATIONS;
+    // Every dependency splits n with probability 1/2 or more, unless n is a prime power.
+    for _ in 0..4 {
+        while sieve.full.len() < wanted {
+            sieve.next_a();
+        }
+        for dependency in dependencies(&sieve.full, columns) {
+            if let Some(d) = square_root(n, &sieve.base, &sieve.full, &dependency) {
+                return Some(d);
+            }
+        }
+        wanted += EXTRA_RELATIONS;
+    }
+    None
+}
+
+// A proper factor of an odd composite n that is not a perfect power, by SIQS.
+// None in the unlikely case that no dependency splits n.
+pub fn siqs<T: Int>(n: &T) -> Option<T> {
+    siqs_with_rng(n, &mut thread_rng())
+}
+
+// SIQS with the primes of each a drawn from rng
+pub fn siqs_with_rng<T: Int, R: Rng + ?Sized>(n: &T, rng: &mut R) -> Option<T> {
+    siqs_bigint(&n.to_bigint(), rng).map(|d| T::from_bigint(&d).expect("a factor of n fits into T"))
+}
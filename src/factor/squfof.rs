@@ -0,0 +1,78 @@
+// Shanks' square form factorization on the continued fraction of sqrt(kN). A square Q_i at
+// an even step gives an ambiguous form after the reverse cycle, whose Q shares a factor with N.
+// Each multiplier k gets about 3 sqrt(2 sqrt(kN)) steps before the next one is tried.
+use crate::utils::{gcd, exact_sqrt};
+
+// Products of small odd primes, for kN with a shorter cycle
+const MULTIPLIERS: [u64; 16] = [
+    1, 3, 5, 7, 11, 3 * 5, 3 * 7, 3 * 11, 5 * 7, 5 * 11, 7 * 11,
+    3 * 5 * 7, 3 * 5 * 11, 3 * 7 * 11, 5 * 7 * 11, 3 * 5 * 7 * 11,
+];
+
+// The forward cycle from P_0 = floor(sqrt(kN)) until a square Q at an even step,
+// then the reverse cycle from its root; a factor of N from the Q where P repeats
+fn squfof_multiplier(n: u64, k: u64) -> Option<u64> {
+    // kN may pass u64, but P and Q stay below 2 sqrt(kN).
+    let kn = k as u128 * n as u128;
+    let p0 = kn.isqrt() as i64;
+    let mut q = (kn - (p0 as u128) * (p0 as u128)) as i64;
+    if q == 0 {
+        return None;
+    }
+    let bound = 3 * ((2 * p0) as u64).isqrt() as i64;
+    let (mut p, mut p_previous, mut q_previous) = (p0, p0, 1);
+    let mut root = None;
+    for i in 2..bound {
+        let b = (p0 + p) / q;
+        p = b * q - p;
+        let q_next = q_previous + b * (p_previous - p);
+        q_previous = q;
+        q = q_next;
+        p_previous = p;
+        if i % 2 == 0 {
+            if let Some(r) = exact_sqrt(&(q as u64)) {
+                root = Some(r as i64);
+                break;
+            }
+        }
+    }
+    let r = root?;
+
+    let b = (p0 - p) / r;
+    p += b * r;
+    let (mut q_previous, mut q) = (r, ((kn - (p as u128) * (p as u128)) / r as u128) as i64);
+    for _ in 0..bound {
+        let b = (p0 + p) / q;
+        let p_previous = p;
+        p = b * q - p;
+        let q_next = q_previous + b * (p_previous - p);
+        q_previous = q;
+        q = q_next;
+        if p == p_previous {
+            break;
+        }
+    }
+    let d = gcd(n, q_previous as u64);
+    (d != 1 && d != n).then_some(d)
+}
+
+// A nontrivial factor of a composite n by SQUFOF, None for primes and when every multiplier fails.
+// Meant for n up to about 62 bits.
+pub fn squfof(n: u64) -> Option<u64> {
+    if n < 4 {
+        return None;
+    }
+    if n.is_multiple_of(2) {
+        return Some(2);
+    }
+    if let Some(r) = exact_sqrt(&n) {
+        return Some(r);
+    }
+    MULTIPLIERS.iter().find_map(|&k| {
+        let d = gcd(n, k);
+        if d != 1 && d != n {
+            return Some(d);
+        }
+        squfof_multiplier(n, k)
+    })
+}
@@ -0,0 +1,1159 @@
+pub mod polynomial;
+mod utils;
+pub mod montgomery;
+pub mod lucas;
+pub mod elliptic_curve;
+pub mod sieve;
+pub mod counting;
+pub mod factor;
+pub mod arith;
+
+pub use utils::*;
+
+pub mod primality;
+
+
+#[cfg(test)]
+mod tests {
+    use num::ToPrimitive;
+    use num::BigInt;
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        let a: u64 = 82289148;
+        let b: u64 = 61345370799;
+        let c: u64 = 25515131265;
+        let n1 = match "12012156372189739281216757210576102012012223892738921798326187361278368721631225467389874637823746372838746543782374637283476543728374637283746543728253243534255432354234523452345234523452345234523452345234523452345234623623523452345234582848248248284"
+                               .parse::<BigInt>() {
+                                    Ok(n) => n,
+                                    Err(_) => panic!("what")
+                                };
+        let n2 = match "123565214386213485217365412738765123873265321983721987398217398217893721983773265812"
+                                .parse::<BigInt>() {
+                                    Ok(n) => n,
+                                    Err(_) => panic!("what")
+                                };
+
+        assert_eq!(gcd(a, a), a);
+        assert_eq!(bgcd(a, a), a);
+        assert_eq!(bgcd(0_u64, a), a);
+        assert_eq!(gcd(0_u64, a), a);
+        assert_eq!(gcd(b, c), 9_u64);
+        assert_eq!(bgcd(b, c), 9_u64);
+        assert_eq!(gcd::<BigInt>(n1, n2).to_i8().expect("not a small enough number"), 76);
+    }
+
+    #[test]
+    fn test_jacobi() {
+        assert_eq!(jacobi(610u64, 987), -1);
+        assert_eq!(jacobi(1001u64, 9907), -1);
+        assert_eq!(jacobi(219u64, 383), 1);
+        assert_eq!(jacobi(3u64, 27), 0);
+        assert_eq!(jacobi(4u64, 11), 1);
+        assert_eq!(jacobi(12u64, 35), 1);
+    }
+
+    #[test]
+    fn test_is_perfect_power() {
+        assert!(is_perfect_kth_power(2*2*2*2*2, 5));
+        assert!(!is_perfect_power(2*2*2*2*2*3));
+    }
+
+    #[test]
+    fn test_polynomial_ops() {
+        let p = &polynomial::Polynomial{coeffs: vec![1,1]};
+        let q = &polynomial::Polynomial{coeffs: vec![1, 2, 1]};
+        let r = &polynomial::Polynomial{coeffs: vec![2, 3, 1]};
+        assert_eq!(p * p, *q);
+        assert_eq!(p + q, *r);
+
+        //  trailing zero test
+        let t = &polynomial::Polynomial{coeffs: vec![1,1]};
+        let u = &polynomial::Polynomial{coeffs: vec![1, 1, 0]};
+        assert_eq!(t, u);
+    }
+
+    #[test]
+    fn test_polynomial_call() {
+        let q = polynomial::Polynomial{coeffs: vec![1, 2, 1]};
+        assert_eq!(q.call(0), 1);
+        assert_eq!(q.call(-1), 0);
+    }
+
+    #[test]
+    fn test_deterministic_primality() {
+        use primality::deterministic::is_prime;
+
+        let primes: [u64; 10] = [2, 3, 97, 101, 7919, 1_000_000_007, 4_294_967_291,
+                                 2_305_843_009_213_693_951, 18_446_744_073_709_551_557,
+                                 18_446_744_073_709_551_533];
+        for p in primes {
+            assert!(is_prime(p), "{} is prime", p);
+        }
+
+        let composites: [u64; 7] = [0, 1, 4, 561, 10_403, 18_446_743_979_220_271_189, u64::MAX];
+        for n in composites {
+            assert!(!is_prime(n), "{} is composite", n);
+        }
+
+        // Strong pseudoprimes to the smallest bases, each defeating a shorter base set.
+        let strong_pseudoprimes: [u64; 12] = [
+            2_047, 3_277, 4_033, 4_681, 8_321, 1_373_653, 25_326_001, 3_215_031_751,
+            2_152_302_898_747, 3_474_749_660_383, 341_550_071_728_321,
+            3_825_123_056_546_413_051
+        ];
+        for n in strong_pseudoprimes {
+            assert!(!is_prime(n), "{} is a strong pseudoprime", n);
+        }
+
+        // Exhaustive comparison against trial division for small n.
+        for n in 0..20_000u64 {
+            let trial = n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0);
+            assert_eq!(is_prime(n), trial, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn test_bpsw() {
+        use primality::probabilistic::*;
+        use primality::deterministic::is_prime;
+
+        // Strong Lucas pseudoprimes pass the Lucas part but not the base 2 test.
+        let lucas_pseudoprimes: [u64; 12] = [5_459, 5_777, 10_877, 16_109, 18_971, 22_499,
+                                             24_569, 25_199, 40_309, 58_519, 75_077, 97_439];
+        for n in lucas_pseudoprimes {
+            assert!(is_strong_lucas_probable_prime(n), "{} is a strong Lucas pseudoprime", n);
+            assert!(bpsw_primality_test(n).is_composite());
+        }
+
+        for n in 0..20_000u64 {
+            assert_eq!(bpsw_primality_test(n).is_prime(), is_prime(n), "n = {}", n);
+        }
+        for n in [3_825_123_056_546_413_051u64, 18_446_744_073_709_551_557, u64::MAX] {
+            assert_eq!(bpsw_primality_test(n).is_prime(), is_prime(n), "n = {}", n);
+        }
+
+        let two = BigInt::from(2);
+        for p in [89u32, 107, 127, 521] {
+            let mersenne_prime = two.pow(p) - 1;
+            assert!(bpsw_primality_test(mersenne_prime).is_probably_prime());
+        }
+
+        let composites = [
+            two.pow(67u32) - 1,
+            (two.pow(89u32) - 1) * (two.pow(61u32) - 1),
+            // A Carmichael number and strong pseudoprime to base 2.
+            BigInt::from(1_299_837_745_921_707_516_889u128),
+            (two.pow(127u32) - 1) * (two.pow(127u32) - 1),
+        ];
+        for n in composites {
+            assert!(bpsw_primality_test(n.clone()).is_composite(), "{} is composite", n);
+        }
+        assert!(is_strong_probable_prime(BigInt::from(1_299_837_745_921_707_516_889u128), two));
+    }
+
+    #[test]
+    fn test_aks() {
+        use primality::deterministic::{aks, is_prime};
+
+        for n in (0..400u64).chain([1_009, 1_105, 1_729, 3_u64.pow(7), 7_919, 7_921]) {
+            assert_eq!(aks(n).is_prime(), is_prime(n), "n = {}", n);
+        }
+    }
+
+    #[test]
+    #[ignore = "seconds per input in release, much slower in debug"]
+    fn test_aks_20_bits() {
+        use primality::deterministic::aks;
+
+        for n in [999_983u64, 1_046_527, 1_048_573] {
+            assert!(aks(n).is_prime(), "{} is prime", n);
+        }
+        // A semiprime of two 10-bit primes, a Carmichael number and a prime square
+        for n in [1_009u64 * 1_013, 852_841, 1_021 * 1_021] {
+            assert!(aks(n).is_composite(), "{} is composite", n);
+        }
+    }
+
+    #[test]
+    fn test_polynomial_mul_mod() {
+        // (x + 1)^5 = x^5 + 5x^4 + 10x^3 + 10x^2 + 5x + 1 = 4x^2 + 3x + 4 mod (x^3 - 1, 7)
+        let p = polynomial::Polynomial{coeffs: vec![1u64, 1]};
+        assert_eq!(p.pow_mod(5, 3, &7), polynomial::Polynomial{coeffs: vec![4, 3, 4]});
+        assert_eq!(p.mul_mod(&p, 2, &7), polynomial::Polynomial{coeffs: vec![2, 2]});
+    }
+
+    #[test]
+    fn test_mod_exp_near_max() {
+        use primality::probabilistic::is_strong_probable_prime;
+
+        let p: u64 = u64::MAX - 58; // the largest prime below 2^64
+        assert_eq!(mul_mod(u64::MAX, u64::MAX, p), 3_364);
+        assert_eq!(mod_exp(u64::MAX, 2, p), 3_364);
+        assert_eq!(mod_exp(12_345_678_901_234_567, p - 2, p), 8_297_469_362_529_172_873);
+        assert_eq!(mod_exp(2, u64::MAX, 1), 0);
+        assert_eq!(mod_exp(dumb_mod_exp(7, 1_000, p), 1, p), mod_exp(7, 1_000, p));
+        for a in [2, 3, 5, u64::MAX - 1, p - 1] {
+            assert_eq!(mod_exp(a, p - 1, p), 1);
+            let euler = match jacobi(a, p) { 1 => 1, _ => p - 1 };
+            assert_eq!(mod_exp(a, (p - 1) / 2, p), euler);
+            assert!(is_strong_probable_prime(p, a));
+        }
+        assert!(!is_strong_probable_prime(u64::MAX, 2));
+        assert!(!is_strong_probable_prime(4_294_967_291u64 * 4_294_967_279, 2));
+
+        let q: u128 = u128::MAX - 158; // the largest prime below 2^128
+        assert_eq!(mul_mod_u128(u128::MAX, u128::MAX - 2, q), 24_648);
+        let m: u128 = (1 << 127) - 1;
+        assert_eq!(mod_exp(3, m - 1, m), 1);
+        assert_eq!(mod_exp((1 << 126) + 12_345, (1 << 100) + 7, m),
+                   5_492_281_334_921_474_124_414_515_163_314_935_855);
+        assert_eq!(mul_mod_u128(u64::MAX as u128, u64::MAX as u128, p as u128), 3_364);
+        // Operands past 2^64 with a modulus below it are reduced in full, not truncated.
+        assert_eq!(mul_mod_u128(u128::MAX, (1 << 64) + 3, 1_000_000_007), 71_003_776);
+        assert_eq!(mul_mod_u128((1 << 64) + 6, 3, 7), 3);
+    }
+
+    #[test]
+    fn test_generic_integers() {
+        use num::BigUint;
+        use primality::probabilistic::*;
+
+        let p = BigUint::from(2u8).pow(127u32) - 1u8;
+        let q = BigUint::from(2u8).pow(89u32) - 1u8;
+        assert_eq!(mod_exp(BigUint::from(3u8), p.clone() - 1u8, p.clone()), BigUint::from(1u8));
+        assert_eq!(mod_exp(BigUint::from(12_345u32), q.clone(), p.clone()),
+                   BigUint::from(12_345u32).modpow(&q, &p));
+        assert_eq!(mod_exp(BigInt::from(-2), BigInt::from(3), BigInt::from(7)), BigInt::from(6));
+        assert_eq!(mod_exp(3u32, 4_000_000_000, 4_294_967_291), mod_exp(3u64, 4_000_000_000, 4_294_967_291) as u32);
+
+        assert_eq!(jacobi(BigInt::from(-1), BigInt::from(7)), -1);
+        assert_eq!(jacobi(BigUint::from(610u32), BigUint::from(987u32)), -1);
+        assert_eq!(jacobi(1001u32, 9907), -1);
+        assert_eq!(jacobi(219u128, 383), 1);
+
+        let composite = p.clone() * q.clone();
+        assert!(fermat_primality_test(p.clone(), 10).is_probably_prime());
+        assert!(fermat_primality_test(composite.clone(), 10).is_composite());
+        assert!(solovay_strassen_primality_test(p.clone(), 10).is_probably_prime());
+        assert!(solovay_strassen_primality_test(composite.clone(), 10).is_composite());
+        assert!(miller_rabin_primality_test(BigInt::from(q.clone()), 10).is_probably_prime());
+        assert!(miller_rabin_primality_test(BigInt::from(composite), 10).is_composite());
+
+        assert!(miller_rabin_primality_test(u128::MAX - 158, 10).is_probably_prime());
+        assert!(miller_rabin_primality_test((1u128 << 127) + 1, 10).is_composite());
+        assert!(miller_rabin_primality_test(4_294_967_291u32, 10).is_probably_prime());
+        assert!(miller_rabin_primality_test(101u32, 200).is_prime());
+        // More rounds than a u32 holds
+        assert!(miller_rabin_primality_test(101u32, 1 << 40).is_prime());
+        assert!(miller_rabin_primality_test(561u32, 1 << 40).is_composite());
+        // With every base tried the other tests are exact as well, Carmichael numbers included.
+        assert_eq!(fermat_primality_test(101u32, 200), primality::Primality::Prime(None));
+        assert_eq!(solovay_strassen_primality_test(101u32, 200), primality::Primality::Prime(None));
+        assert!(fermat_primality_test(561u32, 600).is_composite());
+        assert!(solovay_strassen_primality_test(561u32, 600).is_composite());
+        assert!(bpsw_primality_test(u128::MAX - 158).is_probably_prime());
+        assert!(bpsw_primality_test(p).is_probably_prime());
+    }
+
+    #[test]
+    fn test_montgomery() {
+        use rand::{Rng, SeedableRng, rngs::StdRng};
+        use montgomery::{ModularContext, Montgomery};
+
+        let mut rng = StdRng::seed_from_u64(1);
+        for n in [3u64, 1_000_000_007, u64::MAX - 58, u64::MAX, (1 << 63) + 1] {
+            let ctx = Montgomery::<u64>::new(n);
+            for _ in 0..100 {
+                let (a, b, e) = (rng.gen::<u64>() % n, rng.gen::<u64>() % n, rng.gen::<u64>());
+                let (a_form, b_form) = (ctx.to_form(a), ctx.to_form(b));
+                assert_eq!(ctx.to_residue(a_form), a);
+                assert_eq!(ctx.to_residue(ctx.mul(&a_form, &b_form)), mul_mod(a, b, n));
+                assert_eq!(ctx.to_residue(ctx.square(&a_form)), mul_mod(a, a, n));
+                assert_eq!(ctx.to_residue(ctx.pow(&a_form, &e)), mod_exp(a, e, n));
+            }
+            assert_eq!(ctx.to_residue(ctx.one()), 1 % n);
+        }
+
+        for n in [5u128, u64::MAX as u128 + 2, u128::MAX - 158, u128::MAX] {
+            let ctx = Montgomery::<u128>::new(n);
+            for _ in 0..20 {
+                let (a, b, e) = (rng.gen::<u128>() % n, rng.gen::<u128>() % n, rng.gen::<u64>() as u128);
+                let (a_form, b_form) = (ctx.to_form(a), ctx.to_form(b));
+                assert_eq!(ctx.to_residue(a_form), a);
+                assert_eq!(ctx.to_residue(ctx.mul(&a_form, &b_form)), mul_mod_u128(a, b, n));
+                assert_eq!(ctx.to_residue(ctx.pow(&a_form, &e)), mod_exp(a, e, n));
+            }
+        }
+    }
+
+    #[test]
+    fn test_probabilistic_with_rng() {
+        use rand::{SeedableRng, rngs::StdRng};
+        use primality::Primality;
+        use primality::probabilistic::*;
+
+        let is_composite = |verdict: Primality<u64>| verdict.is_composite();
+
+        for n in 0..4u64 {
+            let prime = n >= 2;
+            assert_eq!(!is_composite(fermat_primality_test(n, 5)), prime);
+            assert_eq!(!is_composite(solovay_strassen_primality_test(n, 5)), prime);
+            assert_eq!(!is_composite(miller_rabin_primality_test(n, 5)), prime);
+            assert_eq!(!is_composite(bpsw_primality_test(n)), prime);
+        }
+        assert!(miller_rabin_primality_test(2u64, 5).is_prime());
+
+        // A seeded generator reproduces the bases, and with them the verdict,
+        // even where it depends on the bases (a Carmichael number under Fermat).
+        for seed in 0..20 {
+            let first = fermat_primality_test_with_rng(1_105u64, 2, &mut StdRng::seed_from_u64(seed));
+            let second = fermat_primality_test_with_rng(1_105u64, 2, &mut StdRng::seed_from_u64(seed));
+            assert_eq!(is_composite(first), is_composite(second));
+        }
+
+        // Large n: the bases are sampled, not collected.
+        let mut rng = StdRng::seed_from_u64(7);
+        let p = u64::MAX - 58;
+        let forty_bits: u64 = 1_099_511_627_689; // 2^40 - 87 is prime
+        for n in [p, forty_bits] {
+            assert!(fermat_primality_test_with_rng(n, 20, &mut rng).is_probably_prime());
+            assert!(solovay_strassen_primality_test_with_rng(n, 20, &mut rng).is_probably_prime());
+            assert!(miller_rabin_primality_test_with_rng(n, 20, &mut rng).is_probably_prime());
+        }
+        for n in [u64::MAX, 4_294_967_291 * 4_294_967_279, forty_bits * 3] {
+            assert!(is_composite(fermat_primality_test_with_rng(n, 20, &mut rng)));
+            assert!(is_composite(solovay_strassen_primality_test_with_rng(n, 20, &mut rng)));
+            assert!(is_composite(miller_rabin_primality_test_with_rng(n, 20, &mut rng)));
+        }
+    }
+
+    #[test]
+    fn test_primality_evidence() {
+        use std::collections::HashSet;
+        use rand::{SeedableRng, rngs::StdRng};
+        use primality::{Certificate, Primality, Witness};
+        use primality::probabilistic::*;
+        use primality::deterministic::{aks, primality_test};
+
+        let mut rng = StdRng::seed_from_u64(3);
+
+        assert_eq!(primality_test(1), Primality::Composite(Witness::SmallerThanTwo));
+        assert_eq!(primality_test(91), Primality::Composite(Witness::Factor(7)));
+        assert_eq!(primality_test(1_373_653), Primality::Composite(Witness::Base(5)));
+        assert_eq!(primality_test(97), Primality::Prime(Some(Certificate::TrialDivision)));
+        assert_eq!(primality_test(1_000_003),
+                   Primality::Prime(Some(Certificate::StrongBases(vec![2, 3]))));
+        assert_eq!(aks(3_u64.pow(7)), Primality::Composite(Witness::PerfectPower));
+
+        // 5459 is a strong Lucas pseudoprime, 1093^2 is a strong pseudoprime to base 2.
+        assert_eq!(bpsw_primality_test(5_459u64), Primality::Composite(Witness::Base(2)));
+        assert_eq!(bpsw_primality_test(1_093u64 * 1_093), Primality::Composite(Witness::Factor(1_093)));
+
+        // Whatever base exposes a composite, it really is a witness.
+        for _ in 0..20 {
+            match miller_rabin_primality_test_with_rng(561u64, 5, &mut rng) {
+                Primality::Composite(Witness::Base(a)) => assert!(!is_strong_probable_prime(561, a)),
+                verdict => panic!("unexpected verdict {}", verdict),
+            }
+            match solovay_strassen_primality_test_with_rng(561u64, 5, &mut rng) {
+                Primality::Composite(Witness::Factor(d)) => assert!(d > 1 && d < 561 && 561 % d == 0),
+                Primality::Composite(Witness::Base(a)) => assert_eq!(gcd(a, 561), 1),
+                verdict => panic!("unexpected verdict {}", verdict),
+            }
+        }
+
+        let verdict = miller_rabin_primality_test_with_rng(1_000_000_007u64, 10, &mut rng);
+        assert_eq!(verdict, Primality::ProbablyPrime{ rounds: 10, error_bits: Some(20) });
+        assert_eq!(verdict.to_string(), "probably prime (10 rounds, error below 2^-20)");
+        assert_eq!(fermat_primality_test_with_rng(1_000_000_007u64, 3, &mut rng).to_string(),
+                   "probably prime (3 rounds)");
+        assert_eq!(primality_test(91).to_string(), "composite (divisible by 7)");
+        assert_eq!(primality_test(1_000_003).to_string(), "prime (strong bases 2, 3)");
+
+        let verdicts: HashSet<Primality<u64>> = (0..100).map(primality_test).collect();
+        assert!(verdicts.contains(&Primality::Prime(Some(Certificate::TrialDivision))));
+    }
+
+    #[test]
+    fn test_pratt_certificates() {
+        use num::BigUint;
+        use primality::PrattCertificate;
+        use primality::{Certificate, Primality, Witness};
+        use primality::pratt::{pratt_certificate, pratt_primality_test};
+
+        for p in [2u64, 3, 7, 65_537, 1_000_000_007, 2_305_843_009_213_693_951, u64::MAX - 58] {
+            let certificate = pratt_certificate(p).expect("p is prime");
+            assert_eq!(certificate.n, p);
+            assert!(certificate.verify(), "certificate for {}", p);
+
+            let text = certificate.to_string();
+            assert_eq!(text.parse::<PrattCertificate<u64>>(), Ok(certificate));
+            let big = text.parse::<PrattCertificate<BigUint>>().expect("valid certificate text");
+            assert_eq!(big.n, BigUint::from(p));
+            assert!(big.verify());
+        }
+        assert_eq!(pratt_certificate(7).expect("7 is prime").to_string(), "2 1\n3 2 2^1\n7 3 2^1 3^1");
+
+        for n in [0u64, 1, 561, u64::MAX] {
+            assert_eq!(pratt_certificate(n), None);
+        }
+        assert_eq!(pratt_primality_test(561), Primality::Composite(Witness::Factor(3)));
+        assert!(matches!(pratt_primality_test(65_537), Primality::Prime(Some(Certificate::Pratt(c))) if c.verify()));
+
+        // 560 = 2^4 * 5 * 7, but no element has order 560 modulo 561.
+        for g in 2..20 {
+            let forged = format!("2 1\n3 2 2^1\n5 2 2^2\n7 3 2^1 3^1\n561 {} 2^4 5^1 7^1", g);
+            let forged: PrattCertificate<u64> = forged.parse().expect("well-formed text");
+            assert!(!forged.verify());
+        }
+        let wrong_generator: PrattCertificate<u64> = "2 1\n3 2 2^1\n7 2 2^1 3^1".parse().expect("well-formed text");
+        assert!(!wrong_generator.verify());
+        let incomplete: PrattCertificate<u64> = "2 1\n7 3 2^1".parse().expect("well-formed text");
+        assert!(!incomplete.verify());
+
+        assert!("7 3 2^1 3^1".parse::<PrattCertificate<u64>>().is_err());
+        assert!("2 1\n3 x 2^1".parse::<PrattCertificate<u64>>().is_err());
+        assert!("".parse::<PrattCertificate<u64>>().is_err());
+    }
+
+    #[test]
+    fn test_pocklington() {
+        use num::BigUint;
+        use primality::{Certificate, Primality, Witness};
+        use primality::deterministic::{pocklington_certificate, pocklington_primality_test, PocklingtonFactor};
+
+        for p in [3u64, 65_537, 2_305_843_009_213_693_951, u64::MAX - 58] {
+            assert!(pocklington_certificate(p).expect("p is prime").verify(), "certificate for {}", p);
+        }
+        let mersenne = (BigInt::from(1u8) << 127usize) - 1;
+        assert!(matches!(pocklington_primality_test(mersenne), Primality::Prime(Some(Certificate::Pocklington(c))) if c.verify()));
+
+        // 2q + 1 with a prime q > 2^64, which needs a certificate of its own
+        let safe: BigUint = "2417851639229258349415043".parse().unwrap();
+        let certificate = pocklington_certificate(safe).expect("safe prime");
+        assert!(certificate.verify());
+        assert!(certificate.factors.iter().any(|factor| factor.certificate.is_some()));
+
+        // n - 1 = 2^64 * p1 * p2 with 50-bit primes p1, p2 that rho does not find,
+        // so only F = 2^64 > n^(1/3) is factored and the proof goes through BLS.
+        let bls: BigInt = "12905865865738444556665597863185392570767304032257".parse().unwrap();
+        let certificate = pocklington_certificate(bls.clone()).expect("n - 1 is factored beyond the cube root");
+        assert_eq!(certificate.factors.len(), 1);
+        assert!(certificate.verify());
+
+        assert_eq!(pocklington_primality_test(561u64), Primality::Composite(Witness::Base(2)));
+        assert_eq!(pocklington_certificate(BigInt::from(1u8)), None);
+
+        // The same F = 2^64 part for n + 2, which is not prime
+        let mut forged = certificate.clone();
+        forged.n = bls + 2;
+        assert!(!forged.verify());
+        let mut forged = certificate;
+        forged.factors.push(PocklingtonFactor{ q: BigInt::from(4u8), exponent: 1, base: BigInt::from(3u8), certificate: None });
+        assert!(!forged.verify());
+    }
+
+    #[test]
+    fn test_lucas_lehmer() {
+        use num::BigUint;
+        use primality::{Primality, Witness};
+        use primality::special::{lucas_lehmer, mersenne_candidates, mersenne_exponents, trial_factor_mersenne};
+
+        assert_eq!(mersenne_exponents(0..1300), vec![2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127, 521, 607, 1279]);
+        assert_eq!(lucas_lehmer(11), Primality::Composite(Witness::Residue(BigUint::from(1736u32))));
+        assert_eq!(lucas_lehmer(15), Primality::Composite(Witness::Factor(BigUint::from(7u8))));
+        assert_eq!(lucas_lehmer(1), Primality::Composite(Witness::SmallerThanTwo));
+
+        // 2^11 - 1 = 23 * 89, 2^29 - 1 = 233 * 1103 * 2089
+        assert_eq!(trial_factor_mersenne(11, 10), Some(23));
+        assert_eq!(trial_factor_mersenne(29, 10), Some(233));
+        assert_eq!(trial_factor_mersenne(31, 1 << 20), None);
+        assert_eq!(mersenne_candidates(2..32, 100), vec![2, 3, 5, 7, 13, 17, 19, 31]);
+    }
+
+    #[test]
+    fn test_special_forms() {
+        use num::BigUint;
+        use primality::{Primality, Witness};
+        use primality::special::{llr, pepin, proth};
+
+        let fermat_primes: Vec<u32> = (0..11).filter(|&m| pepin(m).is_prime()).collect();
+        assert_eq!(fermat_primes, vec![0, 1, 2, 3, 4]);
+        assert_eq!(pepin(5), Primality::Composite(Witness::Base(BigUint::from(3u8))));
+
+        let primes = |test: fn(u64, u64) -> Option<Primality<BigUint>>, k: u64, from: u64| -> Vec<u64> {
+            (from..120).filter(|&e| test(k, e).expect("k and e are in range").is_prime()).collect()
+        };
+        assert_eq!(primes(proth, 3, 2), vec![2, 5, 6, 8, 12, 18, 30, 36, 41, 66]);
+        assert_eq!(primes(proth, 5, 3), vec![3, 7, 13, 15, 25, 39, 55, 75, 85]);
+        assert_eq!(primes(llr, 1, 2), vec![2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107]);
+        assert_eq!(primes(llr, 3, 2), vec![2, 3, 4, 6, 7, 11, 18, 34, 38, 43, 55, 64, 76, 94, 103]);
+        assert_eq!(primes(llr, 5, 3), vec![4, 8, 10, 12, 14, 18, 32, 48, 54, 72]);
+
+        // 3 * 2^3 + 1 = 25 and 5 * 2^3 - 1 = 39 = 3 * 13
+        assert_eq!(proth(3, 3), Some(Primality::Composite(Witness::Factor(BigUint::from(5u8)))));
+        assert_eq!(llr(5, 3), Some(Primality::Composite(Witness::Factor(BigUint::from(3u8)))));
+        assert!(matches!(llr(5, 28), Some(Primality::Composite(Witness::Residue(_)))));
+
+        // Even k and k >= 2^e are not of the form, nor is e < 2 for LLR.
+        for (k, e) in [(4, 5), (5, 2), (8, 3), (1, 0), (u64::MAX, 63)] {
+            assert_eq!(proth(k, e), None, "k = {}, e = {}", k, e);
+            assert_eq!(llr(k, e), None, "k = {}, e = {}", k, e);
+        }
+        assert_eq!(llr(1, 1), None);
+        assert_eq!(proth(1, 1), Some(Primality::Prime(None)));
+        assert!(proth(u64::MAX, 64).is_some());
+    }
+
+    #[test]
+    fn test_elliptic_curves() {
+        use elliptic_curve::{Curve, Point};
+
+        for p in [3u64, 5, 13, 17, 97, 65_537, 1_000_000_007] {
+            for a in 0..p.min(50) {
+                match sqrt_mod(a, p) {
+                    Some(root) => assert_eq!(mul_mod(root, root, p), a),
+                    None => assert_eq!(jacobi(a, p), -1),
+                }
+            }
+        }
+        assert_eq!(mod_inverse(&3u64, &7), Ok(5));
+        assert_eq!(mod_inverse(&6u64, &9), Err(3));
+        assert_eq!(mod_inverse(&BigInt::from(-2), &BigInt::from(9)), Ok(BigInt::from(4)));
+
+        // y^2 = x^3 + 2x + 3 has 100 points over F_97.
+        let curve = Curve::new(2u64, 3, 97);
+        assert!(curve.is_nonsingular());
+        let points: Vec<Point<u64>> = (0..97).filter_map(|x| curve.lift_x(x)).collect();
+        for p in &points {
+            assert!(curve.contains(p));
+            assert_eq!(curve.mul(p, &100), Ok(Point::Infinity));
+            assert_eq!(curve.add(p, &curve.negate(p)), Ok(Point::Infinity));
+            let tripled = curve.add(&curve.double(p).unwrap(), p).unwrap();
+            assert_eq!(curve.mul(p, &3), Ok(tripled));
+        }
+
+        // Over Z/(97 * 101)Z some multiple on the way is infinity modulo 101 but not modulo 97,
+        // and the failed inversion reveals 101.
+        let curve = Curve::new(2u64, 3, 97 * 101);
+        assert_eq!(curve.mul(&Point::Affine(3, 6), &100), Err(101));
+    }
+
+    #[test]
+    fn test_ecpp() {
+        use primality::ecpp::{ecpp_certificate, ecpp_primality_test};
+
+        let p: BigInt = "3000000000000000000000000000000000000037".parse().unwrap();
+        let certificate = ecpp_certificate(p.clone()).expect("p is prime");
+        assert_eq!(certificate.n, p);
+        assert!(!certificate.steps.is_empty());
+        assert!(certificate.verify());
+
+        let small = ecpp_certificate(BigInt::from(65_537)).expect("65537 is prime");
+        assert!(small.steps.is_empty() && small.verify());
+        assert!(ecpp_primality_test(&p * &p).is_composite());
+
+        let mut forged = certificate.clone();
+        forged.steps[0].point.1 += 1;
+        assert!(!forged.verify());
+        let mut forged = certificate.clone();
+        forged.steps[0].order *= 2;
+        forged.steps[0].q *= 2;
+        assert!(!forged.verify());
+        let mut forged = certificate;
+        forged.steps.pop();
+        assert!(!forged.verify());
+    }
+
+    #[test]
+    #[ignore = "about 10 s in release"]
+    fn test_ecpp_100_digits() {
+        use primality::ecpp::ecpp_certificate;
+
+        // The first prime after 10^99
+        let p: BigInt = BigInt::from(10).pow(99u32) + 289;
+        let certificate = ecpp_certificate(p.clone()).expect("p is prime");
+        assert_eq!(certificate.n, p);
+        assert!(certificate.steps.len() > 1);
+        assert!(certificate.verify());
+    }
+
+    #[test]
+    fn test_frobenius() {
+        use rand::{SeedableRng, rngs::StdRng};
+        use primality::{Primality, Witness};
+        use primality::deterministic::is_prime;
+        use primality::probabilistic::{frobenius_test, quadratic_frobenius_test_with_rng};
+        use polynomial::Polynomial;
+
+        let n = BigInt::from(1_000_003);
+        let fibonacci = Polynomial{ coeffs: vec![BigInt::from(-1), BigInt::from(-1), BigInt::from(1)] };
+        let cubic = Polynomial{ coeffs: vec![BigInt::from(-1), BigInt::from(-1), BigInt::from(0), BigInt::from(1)] };
+        assert_eq!(fibonacci.discriminant_mod(&n), Ok(BigInt::from(5)));
+        assert_eq!(cubic.discriminant_mod(&n), Ok(&n - 23));
+
+        let mut rng = StdRng::seed_from_u64(7);
+        for k in (5u64..5000).step_by(2).filter(|k| k % 23 != 0) {
+            let big = BigInt::from(k);
+            assert_eq!(frobenius_test(big.clone(), &cubic).is_probably_prime(), is_prime(k), "{}", k);
+            assert_eq!(quadratic_frobenius_test_with_rng(k, 2, &mut rng).is_probably_prime(), is_prime(k), "{}", k);
+            if k % 5 != 0 && k != 4181 {
+                assert_eq!(frobenius_test(big, &fibonacci).is_probably_prime(), is_prime(k), "{}", k);
+            }
+        }
+        // 4181 = 37 * 113 is a Frobenius pseudoprime for x^2 - x - 1.
+        assert!(frobenius_test(BigInt::from(4181), &fibonacci).is_probably_prime());
+
+        let mersenne: BigInt = (BigInt::from(1u8) << 127usize) - 1;
+        assert_eq!(quadratic_frobenius_test_with_rng(mersenne.clone(), 3, &mut rng),
+                   Primality::ProbablyPrime{ rounds: 3, error_bits: Some(36) });
+        assert!(frobenius_test(mersenne, &cubic).is_probably_prime());
+        for n in [561u64, 3_215_031_751, 1093 * 1093 * 3, 5459, 5777] {
+            assert!(quadratic_frobenius_test_with_rng(n, 1, &mut rng).is_composite(), "{}", n);
+        }
+        assert_eq!(quadratic_frobenius_test_with_rng(1_000_003u64 * 1_000_003, 1, &mut rng),
+                   Primality::Composite(Witness::Factor(1_000_003)));
+        assert_eq!(frobenius_test(BigInt::from(2047), &fibonacci), Primality::Composite(Witness::Polynomial(fibonacci.coeffs.clone())));
+        assert_eq!(frobenius_test(BigInt::from(561), &fibonacci), Primality::Composite(Witness::Factor(BigInt::from(33))));
+
+        // The test does not apply when n divides f(0) disc(f) or f has degree 0 mod n.
+        let inconclusive = Primality::ProbablyPrime{ rounds: 0, error_bits: None };
+        assert_eq!(frobenius_test(BigInt::from(5), &fibonacci), inconclusive);
+        assert_eq!(frobenius_test(BigInt::from(23), &cubic), inconclusive);
+        let linear = Polynomial{ coeffs: vec![BigInt::from(5), BigInt::from(7)] };
+        assert_eq!(frobenius_test(BigInt::from(7), &linear), inconclusive);
+        assert_eq!(frobenius_test(BigInt::from(7), &Polynomial{ coeffs: vec![BigInt::from(5)] }), inconclusive);
+        assert_eq!(frobenius_test(BigInt::from(7), &Polynomial{ coeffs: vec![BigInt::from(0), BigInt::from(14)] }), inconclusive);
+        let strategy = primality::strategy::Frobenius{ polynomial: fibonacci.clone() };
+        assert_eq!(primality::PrimalityTest::test(&strategy, BigInt::from(5)), inconclusive);
+        // 1 < gcd(n, f(0) disc(f)) < n is a factor.
+        assert_eq!(frobenius_test(BigInt::from(35), &fibonacci), Primality::Composite(Witness::Factor(BigInt::from(5))));
+
+        // The quadratic test trial divides up to 50000 before its rounds.
+        let semiprime = 49_999u64 * 1_000_003;
+        assert_eq!(quadratic_frobenius_test_with_rng(semiprime, 1, &mut rng), Primality::Composite(Witness::Factor(49_999)));
+    }
+
+    #[test]
+    fn test_primality_strategies() {
+        use primality::{Certificate, Primality, Witness, PrimalityTest, ErrorBound};
+        use primality::strategy::*;
+
+        let fermat_then_mr = PrimalityTest::<u64>::then(Fermat{ rounds: 2 }, MillerRabin{ rounds: 3 });
+        assert_eq!(PrimalityTest::<u64>::name(&fermat_then_mr), "Fermat (2 rounds) then Miller-Rabin (3 rounds)");
+        assert_eq!(PrimalityTest::<u64>::error_bound(&fermat_then_mr), ErrorBound::Bits(6));
+        assert_eq!(fermat_then_mr.test(1_000_003u64), Primality::ProbablyPrime{ rounds: 5, error_bits: Some(6) });
+        assert!(fermat_then_mr.test(1_000_003u64 * 999_983).is_composite());
+
+        let proved = PrimalityTest::<u64>::then(Bpsw, Deterministic);
+        assert_eq!(proved.error_bound(), ErrorBound::Exact);
+        assert!(proved.test(1_000_003u64).is_prime());
+        assert_eq!(proved.test(15u64), Primality::Composite(Witness::Base(2)));
+
+        let first_proof: FirstProof<u64> = FirstProof{ tests: vec![Box::new(MillerRabin{ rounds: 4 }),
+                                                                   Box::new(Pocklington),
+                                                                   Box::new(Pratt)] };
+        assert_eq!(first_proof.error_bound(), ErrorBound::Exact);
+        assert!(matches!(first_proof.test(1_000_003), Primality::Prime(Some(Certificate::Pocklington(_)))));
+        assert!(first_proof.test(561).is_composite());
+
+        // Fermat is fooled by a Carmichael number, the other two are not
+        let vote: MajorityVote<u64> = MajorityVote{ tests: vec![Box::new(Fermat{ rounds: 1 }),
+                                                              Box::new(Deterministic),
+                                                              Box::new(Pratt)] };
+        assert_eq!(vote.error_bound(), ErrorBound::Unknown);
+        assert!(vote.test(561).is_composite());
+        assert!(vote.test(1_000_003).is_prime());
+
+        let big: Vec<Box<dyn PrimalityTest<BigInt>>> = vec![Box::new(QuadraticFrobenius{ rounds: 1 }),
+                                                            Box::new(SolovayStrassen{ rounds: 2 }),
+                                                            Box::new(Ecpp)];
+        let n = BigInt::from(1_000_000_007u64);
+        for test in &big {
+            assert!(!test.test(n.clone()).is_composite(), "{}", test.name());
+            assert!(test.test(&n * 1_000_000_009u64).is_composite(), "{}", test.name());
+        }
+    }
+
+    #[test]
+    fn test_sieve() {
+        use sieve::{Sieve, primes, primes_in, primes_up_to};
+        use primality::deterministic::is_prime;
+
+        assert_eq!(primes_up_to(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert!(primes_up_to(1).is_empty());
+        let sieve = Sieve::new(10_000_000);
+        assert_eq!(sieve.count(), 664_579);
+        assert_eq!(Sieve::new(1_000_000).iter().count(), 78_498);
+        for n in 0..20_000 {
+            assert_eq!(sieve.is_prime(n), is_prime(n), "n = {}", n);
+        }
+
+        // The segments agree with the sieve, across segment boundaries too
+        assert!(primes().take_while(|&p| p <= 10_000_000).eq(sieve.iter()));
+        for (lo, hi) in [(0, 2), (2, 3), (6, 7), (7, 8), (1_000_000, 1_000_100), (983_000, 3_000_000)] {
+            assert!(primes_in(lo..hi).eq(sieve.iter().filter(|&p| lo <= p && p < hi)), "{}..{}", lo, hi);
+        }
+
+        // Near 2^64 the survivors are confirmed with the deterministic test
+        let top: Vec<u64> = primes_in(u64::MAX - 200..u64::MAX).collect();
+        assert_eq!(top, (u64::MAX - 200..u64::MAX).filter(|&n| is_prime(n)).collect::<Vec<u64>>());
+        assert_eq!(top.last(), Some(&(u64::MAX - 58)));
+        let lo = 1u64 << 50;
+        assert!(primes_in(lo..lo + 10_000).eq((lo..lo + 10_000).filter(|&n| is_prime(n))));
+    }
+
+    #[test]
+    fn test_prime_counting() {
+        use counting::{li, nth_prime, prime_pi};
+        use sieve::Sieve;
+
+        let pi_powers_of_ten: [u64; 13] = [0, 4, 25, 168, 1_229, 9_592, 78_498, 664_579, 5_761_455,
+                                           50_847_534, 455_052_511, 4_118_054_813, 37_607_912_018];
+        for (k, &pi) in pi_powers_of_ten.iter().enumerate() {
+            assert_eq!(prime_pi(10u64.pow(k as u32)), pi, "pi(10^{})", k);
+        }
+        // Across the switch from the sieve to the combinatorial method
+        let sieve = Sieve::new(3_000_000);
+        for x in [(1 << 20) - 1, 1 << 20, 1_048_583, 1_234_567, 2_999_999] {
+            assert_eq!(prime_pi(x), sieve.iter().take_while(|&p| p <= x).count() as u64, "pi({})", x);
+        }
+
+        assert!((li(1e6) - 78_627.55).abs() < 0.01);
+        assert!((li(1e12) - 37_607_950_280.8).abs() < 1.0);
+        let nth: [(u64, u64); 8] = [(1, 2), (2, 3), (3, 5), (10, 29), (1_000, 7_919), (99_999, 1_299_689),
+                                    (1_000_000, 15_485_863), (100_000_000, 2_038_074_743)];
+        for (n, p) in nth {
+            assert_eq!(nth_prime(n), p, "p_{}", n);
+        }
+    }
+
+    #[test]
+    fn test_prime_generation() {
+        use num::{BigUint, Integer};
+        use rand::{SeedableRng, rngs::StdRng};
+        use primality::generation::*;
+        use primality::deterministic::is_prime;
+        use primality::probabilistic::bpsw_primality_test;
+
+        let primes: Vec<u64> = sieve::primes_up_to(20_000);
+        for n in 0..19_997u64 {
+            let next = primes.iter().find(|&&p| p > n).copied();
+            let prev = primes.iter().rev().find(|&&p| p < n).copied();
+            assert_eq!(next_prime(&n), next, "next_prime({})", n);
+            assert_eq!(prev_prime(&n), prev, "prev_prime({})", n);
+        }
+        // Past the small primes the windows are sieved.
+        let window: Vec<u64> = sieve::primes_in(1_000_000_000..1_000_010_000).collect();
+        for pair in window.windows(2) {
+            assert_eq!(next_prime(&pair[0]), Some(pair[1]));
+            assert_eq!(prev_prime(&pair[1]), Some(pair[0]));
+            assert_eq!(next_prime(&(pair[1] - 1)), Some(pair[1]));
+        }
+
+        let largest = u64::MAX - 58;
+        assert_eq!(next_prime(&(largest - 20)), Some(largest));
+        assert_eq!(next_prime(&largest), None);
+        assert_eq!(prev_prime(&u64::MAX), Some(largest));
+        assert_eq!(next_prime(&(u32::MAX - 5)), Some(u32::MAX - 4));
+        assert_eq!(next_prime(&(u32::MAX - 4)), None);
+
+        let two = BigUint::from(2u8);
+        let m127 = two.pow(127u32) - 1u8;
+        assert_eq!(next_prime(&(&m127 - 2u8)), Some(m127.clone()));
+        assert_eq!(prev_prime(&(&m127 + 1u8)), Some(m127.clone()));
+        assert_eq!(prev_prime(&two.pow(89u32)), Some(two.pow(89u32) - 1u8));
+
+        let mut rng = StdRng::seed_from_u64(18);
+        for bits in 2..=64 {
+            let p: u64 = random_prime(bits, &mut rng);
+            assert!(p.bits() == bits as u64 && is_prime(p), "{} bits: {}", bits, p);
+        }
+        for bits in 3..=48 {
+            let p: u64 = random_safe_prime(bits, &mut rng);
+            assert!(p.bits() == bits as u64 && is_prime(p) && is_prime((p - 1) / 2), "{} bits: {}", bits, p);
+        }
+        let p: BigUint = random_prime(512, &mut rng);
+        assert!(p.bits() == 512 && bpsw_primality_test(p).is_probably_prime());
+        let p: BigUint = random_safe_prime(160, &mut rng);
+        assert_eq!(p.bits(), 160);
+        assert!(bpsw_primality_test(p.clone()).is_probably_prime());
+        assert!(bpsw_primality_test((p - 1u8) / 2u8).is_probably_prime());
+
+        let p: u64 = random_prime_congruent(&3, &4, 64, &mut rng).expect("there are primes 3 mod 4");
+        assert!(p.bits() == 64 && p % 4 == 3 && is_prime(p));
+        let p = random_prime_congruent(&1, &1_000_000, 40, &mut rng).expect("there are primes 1 mod 10^6");
+        assert!(p.bits() == 40 && p % 1_000_000 == 1 && is_prime(p));
+        let m = BigUint::from(1_000_003u32);
+        let p = random_prime_congruent(&BigUint::from(7u8), &m, 200, &mut rng).expect("there are primes 7 mod 1000003");
+        assert!(p.bits() == 200 && p.mod_floor(&m) == BigUint::from(7u8));
+        assert!(bpsw_primality_test(p).is_probably_prime());
+        for _ in 0..20 {
+            let p: u64 = random_prime_congruent(&1, &3, 5, &mut rng).expect("19 and 31 are 1 mod 3");
+            assert!(p == 19 || p == 31);
+        }
+        assert_eq!(random_prime_congruent(&2u64, &4, 32, &mut rng), None);
+        // A modulus as large as the range leaves a single candidate.
+        assert_eq!(random_prime_congruent(&(largest - (1 << 63)), &(1 << 63), 64, &mut rng), Some(largest));
+        // 3009 = 3 * 17 * 59 and 4009 = 19 * 211 are the only 12-bit candidates.
+        assert_eq!(random_prime_congruent(&9u64, &1_000, 12, &mut rng), None);
+    }
+
+    #[test]
+    fn test_factorization() {
+        use factor::*;
+        use primality::deterministic::is_prime;
+        use rand::{Rng, SeedableRng, rngs::StdRng};
+
+        let f = factorize(360u64);
+        assert_eq!(f.iter().collect::<Vec<(&u64, u32)>>(), vec![(&2, 3), (&3, 2), (&5, 1)]);
+        assert_eq!(f.to_string(), "2^3 * 3^2 * 5");
+        assert_eq!(f.exponent(&7), 0);
+        assert!(factorize(1u64).is_empty());
+        assert_eq!(factorize(1u64).to_string(), "1");
+        assert_eq!(factorize(u64::MAX).to_string(), "3 * 5 * 17 * 257 * 641 * 65537 * 6700417");
+        assert_eq!(factorize(u64::MAX - 58).to_string(), "18446744073709551557");
+        // Two 32-bit primes, a square of a prime past the trial division and a prime power
+        assert_eq!(factorize(4_294_967_291u64 * 4_294_967_279).to_string(), "4294967279 * 4294967291");
+        assert_eq!(factorize(4_294_967_291u64 * 4_294_967_291).to_string(), "4294967291^2");
+        assert_eq!(factorize(7_919u64.pow(4) * 2).to_string(), "2 * 7919^4");
+        assert_eq!(factorize(1_000_003u128.pow(5) * 999_983).to_string(), "999983 * 1000003^5");
+        // 2^64 + 1 and a product of two 40-bit primes in u128
+        assert_eq!(factorize((1u128 << 64) + 1).to_string(), "274177 * 67280421310721");
+        assert_eq!(factorize(1_099_511_627_689u128 * 1_099_511_627_791).to_string(), "1099511627689 * 1099511627791");
+
+        let mut rng = StdRng::seed_from_u64(19);
+        for _ in 0..200 {
+            let n: u64 = rng.gen_range(2..u64::MAX);
+            let f = factorize(n);
+            let mut product = 1u64;
+            for (p, e) in f.iter() {
+                assert!(is_prime(*p), "{} in {}", p, n);
+                product *= p.pow(e);
+            }
+            assert_eq!(product, n);
+        }
+
+        // p - 1 = 2^2 * 113 * 163 * 241 * 1733 while q - 1 has the factor 22605091.
+        let (p, q) = (30_771_002_429u64, 4_294_967_291u64);
+        assert_eq!(pollard_pm1(&(p as u128 * q as u128), 300, 2_000), Some(p as u128));
+        assert_eq!(pollard_pm1(&(p as u128 * q as u128), 100, 200), None);
+        assert_eq!(brent_rho(&(1_000_003u64 * 999_983), &1, 1 << 20).map(|d| d.min(1_000_003 * 999_983 / d)), Some(999_983));
+    }
+
+    #[test]
+    fn test_ecm() {
+        use factor::*;
+        use primality::generation::next_prime;
+        use num::BigInt;
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let prime_after = |n: &str| next_prime(&n.parse::<BigInt>().unwrap()).unwrap();
+        let p = prime_after("100000000000");
+        let q = prime_after("1000000000000000000000000000");
+        let n = p.clone() * q.clone();
+        let params = EcmParameters::for_digits(12);
+        assert_eq!(params, EcmParameters{ b1: 2_000, b2: 200_000, curves: 25 });
+        let d = ecm(&n, &params, &mut StdRng::seed_from_u64(20)).expect("a 12-digit factor within 25 curves");
+        assert_eq!(d, p);
+        // The same seed picks the same curves.
+        assert_eq!(ecm(&n, &params, &mut StdRng::seed_from_u64(20)), Some(d));
+        assert_eq!(ecm(&n, &EcmParameters{ b1: 10, b2: 20, curves: 3 }, &mut StdRng::seed_from_u64(20)), None);
+
+        // Two primes of 19 digits are past the budget of rho.
+        let (r, s) = (next_prime(&1_000_000_000_000_000_000u128).unwrap(), next_prime(&3_000_000_000_000_000_000u128).unwrap());
+        let f = factorize_with_rng(r * s, &mut StdRng::seed_from_u64(20));
+        assert_eq!(f.primes().copied().collect::<Vec<u128>>(), vec![r, s]);
+        let f = factorize_with_rng(BigInt::from(r) * BigInt::from(s) * p.clone(), &mut StdRng::seed_from_u64(20));
+        assert_eq!(f.primes().cloned().collect::<Vec<BigInt>>(), vec![p, BigInt::from(r), BigInt::from(s)]);
+    }
+
+    #[test]
+    fn test_siqs() {
+        use factor::*;
+        use primality::generation::next_prime;
+        use num::BigInt;
+        use rand::{SeedableRng, rngs::StdRng};
+
+        let prime_after = |n: &str| next_prime(&n.parse::<BigInt>().unwrap()).unwrap();
+        // Balanced semiprimes of 20, 30 and 40 digits
+        for digits in [10, 15, 20] {
+            let p = prime_after(&format!("1{}", "0".repeat(digits - 1)));
+            let q = prime_after(&format!("7{}", "0".repeat(digits - 1)));
+            let d = siqs_with_rng(&(&p * &q), &mut StdRng::seed_from_u64(21)).expect("a dependency splits pq");
+            assert!(d == p || d == q, "{} for {} * {}", d, p, q);
+        }
+        let n = 1_000_000_007u128 * 998_244_353;
+        assert!(matches!(siqs(&n), Some(1_000_000_007 | 998_244_353)));
+
+        // The conversions the sieve goes through
+        assert_eq!(u128::from_bigint(&u128::MAX.to_bigint()), Some(u128::MAX));
+        assert_eq!(u64::from_bigint(&u128::MAX.to_bigint()), None);
+        assert_eq!(num::BigUint::from_bigint(&BigInt::from(-1)), None);
+    }
+
+    #[test]
+    #[ignore = "about 10 s in release"]
+    fn test_siqs_60_digits() {
+        use factor::siqs_with_rng;
+        use primality::generation::next_prime;
+        use num::BigInt;
+        use rand::{SeedableRng, rngs::StdRng};
+        use std::time::{Duration, Instant};
+
+        // Two 30-digit primes with a 60-digit product
+        let p = next_prime(&(BigInt::from(3) * BigInt::from(10).pow(29u32))).unwrap();
+        let q = next_prime(&(BigInt::from(4) * BigInt::from(10).pow(29u32))).unwrap();
+        assert_eq!((&p * &q).to_string().len(), 60);
+        let start = Instant::now();
+        let d = siqs_with_rng(&(&p * &q), &mut StdRng::seed_from_u64(21)).expect("a dependency splits pq");
+        assert!(d == p || d == q, "{} for {} * {}", d, p, q);
+        assert!(start.elapsed() < Duration::from_secs(60), "took {:?}", start.elapsed());
+    }
+
+    #[test]
+    fn test_integer_roots() {
+        assert_eq!(integer_root(&u64::MAX, 2), u32::MAX as u64);
+        assert_eq!(integer_root(&u64::MAX, 3), 2_642_245);
+        assert_eq!(integer_root(&(1u64 << 63), 63), 2);
+        assert_eq!(integer_root(&0u64, 5), 0);
+        assert_eq!(integer_root(&u128::MAX, 2), u64::MAX as u128);
+        // r^k <= n < (r + 1)^k for every k up to the width
+        assert_eq!(integer_root(&u64::MAX, 1), u64::MAX);
+        for k in 2..=64 {
+            let r = integer_root(&u64::MAX, k);
+            assert!(r.checked_pow(k).is_some() && (r + 1).checked_pow(k).is_none(), "k = {}", k);
+        }
+        for k in 2..=128 {
+            let r = integer_root(&u128::MAX, k);
+            assert!(r.checked_pow(k).is_some() && (r + 1).checked_pow(k).is_none(), "k = {}", k);
+        }
+        assert_eq!(integer_root(&u32::MAX, 31), 2);
+        assert_eq!(integer_root(&u32::MAX, 32), 1);
+        // Near 2^64 the old Newton step overflowed.
+        assert!(is_perfect_kth_power(4_294_967_291u64 * 4_294_967_291, 2));
+        assert!(is_perfect_kth_power(2_642_245u64.pow(3), 3));
+        assert!(!is_perfect_kth_power(u64::MAX, 2));
+        assert!(!is_perfect_kth_power(u64::MAX - 1, 3));
+        assert!(is_perfect_power(3u64.pow(40)));
+        for n in 0..10_000u64 {
+            assert_eq!(exact_sqrt(&n).is_some(), is_perfect_square(&n), "n = {}", n);
+        }
+        assert_eq!(exact_sqrt(&(u32::MAX as u64 * u32::MAX as u64)), Some(u32::MAX as u64));
+        assert_eq!(exact_root(&BigInt::from(10).pow(60), 3), Some(BigInt::from(10).pow(20)));
+    }
+
+    #[test]
+    fn test_small_factoring() {
+        use factor::*;
+        use primality::deterministic::is_prime;
+        use rand::{Rng, SeedableRng, rngs::StdRng};
+
+        let splits = |n: u64, d: Option<u64>| matches!(d, Some(d) if d > 1 && d < n && n.is_multiple_of(d));
+        // Two 31-bit primes, a 26-bit factor of a 62-bit number, and a square
+        let composites = [2_147_483_647u64 * 2_147_483_629, 67_108_859 * 68_718_952_447, 1_000_003 * 1_000_003, 91, 1_001];
+        for n in composites {
+            assert!(splits(n, squfof(n)), "squfof({})", n);
+            assert!(splits(n, hart_olf(n, 1 << 20)), "hart_olf({})", n);
+            assert!(splits(n, small_factor(n)), "small_factor({})", n);
+        }
+        for n in [1_000_003u64 * 999_983, 4_294_967_291 * 65_537, 91, 1_000_003 * 1_000_003, 4, 6] {
+            assert!(splits(n, lehman(n)), "lehman({})", n);
+        }
+        // Fermat finds close factors at once, far ones not within the budget.
+        assert_eq!(fermat(4_294_967_291 * 4_294_967_279, 1), Some(4_294_967_279));
+        assert_eq!(fermat(1_000_003 * 3, 100), None);
+        for p in [2u64, 3, 65_537, 4_294_967_291, u64::MAX - 58] {
+            assert_eq!(squfof(p), None);
+            assert_eq!(small_factor(p), None);
+        }
+        assert_eq!(lehman(1_000_003), None);
+        assert_eq!(small_factor(1), None);
+        assert_eq!(small_factor(1 << 40), Some(2));
+
+        let mut rng = StdRng::seed_from_u64(22);
+        for bits in [20, 28, 40, 62] {
+            for _ in 0..50 {
+                let n = rng.gen_range(1u64 << (bits - 1)..1 << bits) | 1;
+                if !is_prime(n) {
+                    assert!(splits(n, small_factor(n)), "small_factor({})", n);
+                    assert!(splits(n, squfof(n)), "squfof({})", n);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lucas_sequences() {
+        use lucas::{lucas_sequence, lucas_v};
+        use num::{BigUint, One};
+        use montgomery::{Montgomery, PlainModulus, ModularContext};
+
+        // Against the recurrence X_(k+1) = P X_k - Q X_(k-1), with Q as n - 3 for Q = -3
+        let n: u64 = 1_000_003;
+        for (p, q) in [(1u64, n - 1), (3, 1), (5, n - 3), (7, 11)] {
+            let (mut u, mut v) = ((0u64, 1u64), (2u64, p));
+            for k in 0..200u64 {
+                let q_k = mod_exp(q, k, n);
+                assert_eq!(lucas_sequence(&p, &q, &k, &n), (u.0, v.0, q_k), "P = {}, Q = {}, k = {}", p, q, k);
+                let step = |(a, b): (u64, u64)| (b, (p * b % n + n - q * a % n) % n);
+                u = step(u);
+                v = step(v);
+            }
+        }
+
+        // The V ladder agrees in every form with V_k(P, 1) from the full sequence.
+        let n = BigUint::from(1_000_000_007u64) * 998_244_353u64;
+        let plain = PlainModulus::new(n.clone());
+        let montgomery = Montgomery::new(1_000_000_007u64);
+        for k in [0u64, 1, 2, 3, 100, 65_537, u64::MAX] {
+            let (_, v, _) = lucas_sequence(&BigUint::from(4u8), &BigUint::one(), &BigUint::from(k), &n);
+            assert_eq!(lucas_v(&plain, &BigUint::from(4u8), &BigUint::from(k)), v);
+            let v = v % 1_000_000_007u64;
+            let ladder = lucas_v(&montgomery, &montgomery.to_form(4), &k);
+            assert_eq!(BigUint::from(montgomery.to_residue(ladder)), v);
+        }
+    }
+
+    #[test]
+    fn test_williams_pp1() {
+        use factor::{williams_pp1, williams_pp1_seed, pollard_pm1};
+        use num::BigUint;
+
+        // p + 1 is 1000-smooth for the first factor and 1000-smooth times 50021 for the third,
+        // while p - 1 of every factor here has a prime past 10^6.
+        let smooth: u128 = 8_408_308_858_393;
+        let stage_two = BigUint::from(362_138_609_957_039_121_421u128);
+        let (q, r) = (4_611_686_018_427_388_039u128, "1267650600228229401496703206393".parse::<BigUint>().unwrap());
+
+        let n = smooth * q;
+        assert_eq!(pollard_pm1(&n, 1_000, 100_000), None);
+        assert_eq!(williams_pp1(&n, 1_000, 1_000, 8), Some(smooth));
+        let n = &stage_two * &r;
+        assert_eq!(pollard_pm1(&n, 1_000, 100_000), None);
+        assert_eq!(williams_pp1(&n, 1_000, 1_000, 8), None);
+        assert_eq!(williams_pp1(&n, 1_000, 100_000, 8), Some(stage_two));
+
+        // Only seeds with D = P_0^2 - 4 a non-residue mod p find it.
+        let found: Vec<bool> = [3u64, 4, 5, 6, 8].iter()
+            .map(|&seed| williams_pp1_seed(&(smooth * q), 1_000, 1_000, seed).is_some())
+            .collect();
+        let expected: Vec<bool> = [5u64, 12, 21, 32, 60].iter()
+            .map(|&d| jacobi(d as u128, smooth) == -1)
+            .collect();
+        assert_eq!(found, expected);
+
+        // A prime leaves nothing to find.
+        assert_eq!(williams_pp1(&q, 1_000, 100_000, 8), None);
+
+        let factors: Vec<(u128, u32)> = factor::factorize(smooth * q).into_iter().collect();
+        assert_eq!(factors, vec![(smooth, 1), (q, 1)]);
+    }
+
+    #[test]
+    fn test_divisors() {
+        use factor::factorize;
+        use num::BigUint;
+
+        for n in 1..2_000u64 {
+            let factorization = factorize(n);
+            let brute: Vec<u64> = (1..=n).filter(|d| n.is_multiple_of(*d)).collect();
+            assert_eq!(factorization.value(), n);
+            assert_eq!(factorization.sorted_divisors().collect::<Vec<_>>(), brute, "n = {}", n);
+            let mut unsorted: Vec<u64> = factorization.divisors().collect();
+            unsorted.sort();
+            assert_eq!(unsorted, brute, "n = {}", n);
+
+            let mut unitary: Vec<u64> = factorization.unitary_divisors().collect();
+            unitary.sort();
+            assert_eq!(unitary, brute.iter().copied().filter(|d| gcd(*d, n / d) == 1).collect::<Vec<_>>());
+            let mut squarefree: Vec<u64> = factorization.squarefree_divisors().collect();
+            squarefree.sort();
+            assert_eq!(squarefree, brute.iter().copied().filter(|d| (2..=d.isqrt()).all(|k| !d.is_multiple_of(k * k))).collect::<Vec<_>>());
+
+            let bound = n / 3;
+            assert_eq!(factorization.divisors_up_to(&bound).collect::<Vec<_>>(),
+                       brute.iter().copied().filter(|&d| d <= bound).collect::<Vec<_>>());
+            assert_eq!(factorization.divisor_count(), brute.len() as u64);
+            for k in 0..3 {
+                assert_eq!(factorization.divisor_sum(k), brute.iter().map(|d| d.pow(k)).sum::<u64>());
+            }
+        }
+
+        // 2^10 3^5 1000003 has 11 * 6 * 2 divisors, and only the first few are wanted.
+        let n: BigUint = BigUint::from(1_000_003u64 * 243) << 10u32;
+        let factorization = factorize(n.clone());
+        assert_eq!(factorization.value(), n);
+        assert_eq!(factorization.divisor_count(), 132);
+        assert_eq!(factorization.divisors().count(), 132);
+        let first: Vec<BigUint> = factorization.sorted_divisors().take(6).collect();
+        assert_eq!(first, [1u32, 2, 3, 4, 6, 8].map(BigUint::from));
+        let sigma = BigUint::from((1u64 << 11) - 1) * 364u32 * 1_000_004u32;
+        assert_eq!(factorization.divisor_sum(1), sigma);
+        assert_eq!(factorization.unitary_divisors().count(), 8);
+        assert_eq!(factorization.squarefree_divisors().max(), Some(BigUint::from(6_000_018u64)));
+        assert_eq!(factorization.divisors_up_to(&BigUint::from(1u8)).count(), 1);
+    }
+
+    #[test]
+    fn test_arithmetic_functions() {
+        use arith::*;
+        use factor::factorize;
+
+        const N: u64 = 3_000;
+        let phi = euler_phi_up_to(N);
+        let carmichael = carmichael_lambda_up_to(N);
+        let mu = mobius_up_to(N);
+        let (sigma_0, sigma_1, sigma_2) = (sigma_up_to(N, 0), sigma_up_to(N, 1), sigma_up_to(N, 2));
+        let divisor_counts = tau_up_to(N);
+        let (big, small) = (big_omega_up_to(N), omega_up_to(N));
+        let lambda = liouville_up_to(N);
+        let mangoldt = von_mangoldt_up_to(N);
+        assert_eq!(phi.len(), N as usize + 1);
+        assert_eq!((phi[0], mu[0], mangoldt[0]), (0, 0, 0.0));
+
+        for n in 1..=N {
+            let i = n as usize;
+            // The sieve agrees with the factorization.
+            assert_eq!(phi[i], euler_phi(n), "φ({})", n);
+            assert_eq!(carmichael[i], carmichael_lambda(n), "λ({})", n);
+            assert_eq!(mu[i], mobius(n), "μ({})", n);
+            assert_eq!((sigma_1[i], sigma_2[i]), (sigma(n, 1), sigma(n, 2)), "σ({})", n);
+            assert_eq!(divisor_counts[i], tau(n), "τ({})", n);
+            assert_eq!((big[i], small[i]), (big_omega(n), omega(n)), "Ω({}), ω({})", n, n);
+            assert_eq!(lambda[i], liouville(n), "λ({})", n);
+            assert_eq!(mangoldt[i], von_mangoldt(n), "Λ({})", n);
+
+            // Sums over the divisors
+            let divisors: Vec<usize> = factorize(n).divisors().map(|d| d as usize).collect();
+            assert_eq!(divisors.iter().map(|&d| phi[d]).sum::<u64>(), n);
+            assert_eq!(divisors.iter().map(|&d| mu[d] as i64).sum::<i64>(), (n == 1) as i64);
+            let square = n.isqrt() * n.isqrt() == n;
+            assert_eq!(divisors.iter().map(|&d| lambda[d] as i64).sum::<i64>(), square as i64);
+            let log = divisors.iter().map(|&d| mangoldt[d]).sum::<f64>();
+            assert!((log - (n as f64).ln()).abs() < 1e-9, "Σ Λ(d) = ln {}", n);
+            assert_eq!(divisors.iter().map(|&d| divisor_counts[d] as i64 * mu[i / d] as i64).sum::<i64>(), 1);
+            assert_eq!(sigma_0[i], divisors.len() as u64);
+
+            // λ(n) divides φ(n), and every unit mod n has an order dividing λ(n).
+            assert!(phi[i].is_multiple_of(carmichael[i]));
+            for a in [2u64, 3, 5, 7, n - 1].into_iter().filter(|&a| n > 1 && gcd(a, n) == 1) {
+                assert_eq!(mod_exp(a, carmichael[i], n), 1, "{}^λ({})", a, n);
+            }
+            assert_eq!(lambda[i], if big[i] % 2 == 0 { 1 } else { -1 });
+            assert!(small[i] <= big[i]);
+        }
+
+        assert_eq!((euler_phi(36), carmichael_lambda(561), mobius(30), tau(720)), (12, 80, -1, 30));
+        assert_eq!(carmichael_lambda(1 << 20), 1 << 18);
+        let m61 = (1u64 << 61) - 1;
+        assert_eq!((euler_phi(m61), mobius(m61), sigma(m61, 1)), (m61 - 1, -1, m61 + 1));
+        assert_eq!(euler_phi(1 << 63), 1 << 62);
+        assert_eq!((big_omega(1 << 63), omega(1 << 63), liouville(1 << 63)), (63, 1, -1));
+        assert!((von_mangoldt(3u64.pow(40)) - 3f64.ln()).abs() < 1e-12);
+        assert_eq!(euler_phi_up_to(0), vec![0]);
+        assert_eq!(mobius_up_to(1), vec![0, 1]);
+    }
+}
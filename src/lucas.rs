@@ -0,0 +1,62 @@
+// Lucas sequences modulo n: U_0 = 0, U_1 = 1, V_0 = 2, V_1 = P and X_(k+1) = P X_k - Q X_(k-1).
+// The strong Lucas test needs U and V for general Q, Williams' p + 1 and the Lucas-Lehmer type
+// tests only V with Q = 1, where V_jk = V_j(V_k) and a ladder on V alone is cheaper.
+use crate::utils::{Int, add_mod, sub_mod};
+use crate::montgomery::ModularContext;
+
+// a / 2 mod n for odd n
+fn half<T: Int>(a: T, n: &T) -> T {
+    if a.is_even() {
+        a >> 1
+    } else {
+        // (a + n) / 2 without overflowing
+        (a >> 1) + (n.clone() >> 1) + T::one()
+    }
+}
+
+// (U_k, V_k, Q^k) mod an odd n, for P and Q reduced mod n. Left to right with
+// U_2j = U_j V_j, V_2j = V_j^2 - 2 Q^j and for the odd steps, with D = P^2 - 4 Q,
+// U_2j+1 = (P U_2j + V_2j) / 2, V_2j+1 = (D U_2j + P V_2j) / 2.
+pub fn lucas_sequence<T: Int>(p: &T, q: &T, k: &T, n: &T) -> (T, T, T) {
+    let mul = |a: &T, b: &T| a.mul_mod(b, n);
+    let add = |a: &T, b: &T| add_mod(a, b, n);
+    let sub = |a: &T, b: &T| sub_mod(a, b, n);
+    if k.is_zero() {
+        let two = T::one() + T::one();
+        return (T::zero(), two % n.clone(), T::one() % n.clone());
+    }
+
+    let two_q = add(q, q);
+    let d = sub(&mul(p, p), &add(&two_q, &two_q));
+    let (mut u, mut v, mut q_k) = (T::one() % n.clone(), p.clone(), q.clone());
+    for bit in (0..k.bits() - 1).rev() {
+        u = mul(&u, &v);
+        v = sub(&mul(&v, &v), &add(&q_k, &q_k));
+        q_k = mul(&q_k, &q_k);
+        if k.bit(bit) {
+            let u_next = half(add(&mul(p, &u), &v), n);
+            v = half(add(&mul(&d, &u), &mul(p, &v)), n);
+            u = u_next;
+            q_k = mul(&q_k, q);
+        }
+    }
+    (u, v, q_k)
+}
+
+// V_k(P, 1) for P in the form of ctx, by the ladder on (V_j, V_j+1) with
+// V_2j = V_j^2 - 2 and V_2j+1 = V_j V_j+1 - P
+pub fn lucas_v<T: Int, C: ModularContext<T>>(ctx: &C, p: &T, k: &T) -> T {
+    let n = ctx.modulus();
+    let two = add_mod(&ctx.one(), &ctx.one(), &n);
+    let (mut v, mut w) = (two.clone(), p.clone());
+    for i in (0..k.bits()).rev() {
+        if k.bit(i) {
+            v = sub_mod(&ctx.mul(&v, &w), p, &n);
+            w = sub_mod(&ctx.square(&w), &two, &n);
+        } else {
+            w = sub_mod(&ctx.mul(&v, &w), p, &n);
+            v = sub_mod(&ctx.square(&v), &two, &n);
+        }
+    }
+    v
+}
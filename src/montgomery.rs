@@ -0,0 +1,159 @@
+use crate::utils::{Int, mul_mod, mul_mod_u128};
+
+// Arithmetic modulo a fixed n, with residues kept in some internal form.
+// Values passed to mul, square and pow have to be in that form,
+// to_form converts an ordinary residue into that form, to_residue converts back.
+pub trait ModularContext<T> {
+    fn new(n: T) -> Self;
+
+    fn modulus(&self) -> T;
+
+    fn to_form(&self, a: T) -> T;
+
+    fn to_residue(&self, a: T) -> T;
+
+    fn mul(&self, a: &T, b: &T) -> T;
+
+    fn square(&self, a: &T) -> T {
+        self.mul(a, a)
+    }
+
+    // 1 in internal form
+    fn one(&self) -> T;
+
+    // a^e, with a in internal form
+    fn pow(&self, a: &T, e: &T) -> T where T: Int {
+        let mut result = self.one();
+        for i in (0..e.bits()).rev() {
+            result = self.square(&result);
+            if e.bit(i) {
+                result = self.mul(&result, a);
+            }
+        }
+        result
+    }
+}
+
+
+// Residues stored as is, every product reduced with mul_mod.
+#[derive(Clone, Debug)]
+pub struct PlainModulus<T> {
+    n: T
+}
+
+impl<T: Int> ModularContext<T> for PlainModulus<T> {
+    fn new(n: T) -> Self {
+        PlainModulus{ n }
+    }
+
+    fn modulus(&self) -> T {
+        self.n.clone()
+    }
+
+    fn to_form(&self, a: T) -> T {
+        a.mod_floor(&self.n)
+    }
+
+    fn to_residue(&self, a: T) -> T {
+        a
+    }
+
+    fn mul(&self, a: &T, b: &T) -> T {
+        a.mul_mod(b, &self.n)
+    }
+
+    fn one(&self) -> T {
+        T::one() % self.n.clone()
+    }
+}
+
+
+// Montgomery form a * R mod n with R = 2^BITS, for odd n.
+// A product then needs two multiplications and a shift instead of a division.
+#[derive(Clone, Debug)]
+pub struct Montgomery<T> {
+    n: T,
+    // n^-1 mod R
+    n_inv: T,
+    // R mod n and R^2 mod n
+    r1: T,
+    r2: T,
+}
+
+// (high, low) words of a * b
+fn widening_mul_u64(a: u64, b: u64) -> (u64, u64) {
+    let product = a as u128 * b as u128;
+    ((product >> 64) as u64, product as u64)
+}
+
+fn widening_mul_u128(a: u128, b: u128) -> (u128, u128) {
+    let mask: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & mask);
+    let (b_hi, b_lo) = (b >> 64, b & mask);
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let middle = (lo_lo >> 64) + (hi_lo & mask) + (lo_hi & mask);
+    let low = (middle << 64) | (lo_lo & mask);
+    let high = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (middle >> 64);
+    (high, low)
+}
+
+macro_rules! impl_montgomery {
+    ($t:ty, $widening_mul:ident, $mul_mod:ident) => {
+        impl Montgomery<$t> {
+            // Montgomery reduction: (high * R + low) / R mod n
+            fn redc(&self, (high, low): ($t, $t)) -> $t {
+                let m = low.wrapping_mul(self.n_inv);
+                // high * R + low - m * n is divisible by R, so the low words cancel
+                let (mn_high, _) = $widening_mul(m, self.n);
+                if high >= mn_high {
+                    high - mn_high
+                } else {
+                    self.n - (mn_high - high)
+                }
+            }
+        }
+
+        impl ModularContext<$t> for Montgomery<$t> {
+            fn new(n: $t) -> Self {
+                assert!(n % 2 == 1, "Montgomery form needs an odd modulus.");
+                // Newton's iteration doubles the number of correct low bits,
+                // and n * n = 1 mod 8 for odd n.
+                let mut n_inv: $t = n;
+                while n.wrapping_mul(n_inv) != 1 {
+                    n_inv = n_inv.wrapping_mul((2 as $t).wrapping_sub(n.wrapping_mul(n_inv)));
+                }
+                let r1 = (<$t>::MAX % n + 1) % n;
+                let r2 = $mul_mod(r1, r1, n);
+                Montgomery{ n, n_inv, r1, r2 }
+            }
+
+            fn modulus(&self) -> $t {
+                self.n
+            }
+
+            fn to_form(&self, a: $t) -> $t {
+                self.redc($widening_mul(a % self.n, self.r2))
+            }
+
+            fn to_residue(&self, a: $t) -> $t {
+                self.redc((0, a))
+            }
+
+            fn mul(&self, a: &$t, b: &$t) -> $t {
+                self.redc($widening_mul(*a, *b))
+            }
+
+            fn one(&self) -> $t {
+                self.r1
+            }
+        }
+    }
+}
+
+impl_montgomery!(u64, widening_mul_u64, mul_mod);
+impl_montgomery!(u128, widening_mul_u128, mul_mod_u128);
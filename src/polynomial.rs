@@ -2,6 +2,7 @@ use std::cmp::{max, PartialEq};
 use std::fmt::Display;
 use std::ops::{Add, Mul, Sub, SubAssign, AddAssign, MulAssign, Div, Rem};
 use num::traits::{Zero, One};
+use crate::utils::{Int, add_mod, sub_mod, mod_inverse};
 
 
 #[derive(Clone, Debug)]
@@ -48,7 +49,7 @@ impl<T: Zero + Add<Output = T> + PartialEq + Clone + One + ToString> Display for
         }
 
         let length = result.len();
-        write!(f, "{}", result[..length - 3].to_string())  // leave out " + " at the end 
+        write!(f, "{}", &result[..length - 3])  // leave out " + " at the end 
     }
 }
 
@@ -58,7 +59,7 @@ impl<T: Clone + Zero> Polynomial<T> {
         if n < self.coeffs.len() {
             return self.coeffs[n].clone();
         }
-        return T::zero();
+        T::zero()
     }
 }
 
@@ -104,6 +105,185 @@ impl<T> Polynomial<T> where
     }
 }
 
+// Arithmetic in (Z/nZ)[x] / (x^r - 1)
+impl<T> Polynomial<T> where
+    T: Mul<Output = T> + AddAssign + Rem<Output = T> + Clone + Zero + One + PartialEq {
+    pub fn reduce_mod(&self, r: usize, n: &T) -> Self {
+        let mut coeffs: Vec<T> = vec![T::zero(); r];
+        for (i, coeff) in self.coeffs.iter().enumerate() {
+            coeffs[i % r] += coeff.clone() % n.clone();
+        }
+        Polynomial{ coeffs: coeffs.into_iter().map(|c| c % n.clone()).collect() }
+    }
+
+    // Products are accumulated before being reduced mod n,
+    // so r * (n - 1)^2 has to fit into T.
+    pub fn mul_mod(&self, other: &Self, r: usize, n: &T) -> Self {
+        let lhs = self.reduce_mod(r, n);
+        let rhs = other.reduce_mod(r, n);
+        let mut coeffs: Vec<T> = vec![T::zero(); r];
+
+        for (i, a) in lhs.coeffs.iter().enumerate() {
+            if a.is_zero() {
+                continue;
+            }
+            for (j, b) in rhs.coeffs.iter().enumerate() {
+                let k = if i + j >= r { i + j - r } else { i + j };
+                coeffs[k] += a.clone() * b.clone();
+            }
+        }
+        Polynomial{ coeffs: coeffs.into_iter().map(|c| c % n.clone()).collect() }
+    }
+
+    pub fn pow_mod(&self, mut exponent: u64, r: usize, n: &T) -> Self {
+        let mut result = Polynomial{ coeffs: vec![T::one()] }.reduce_mod(r, n);
+        let mut base = self.reduce_mod(r, n);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul_mod(&base, r, n);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.mul_mod(&base, r, n);
+            }
+        }
+        result
+    }
+}
+
+// Arithmetic in (Z/nZ)[x] / (f) for a monic f, with coefficients in [0, n)
+impl<T: Int> Polynomial<T> {
+    // Quotient and remainder of self divided by the monic polynomial f
+    pub fn div_rem_monic(&self, f: &Self, n: &T) -> (Self, Self) {
+        let f = f.reduce_coefficients(n);
+        let degree = f.coeffs.len() - 1;
+        let mut coeffs: Vec<T> = self.coeffs.iter().map(|c| c.mod_floor(n)).collect();
+        let mut quotient = vec![T::zero(); coeffs.len().saturating_sub(degree).max(1)];
+        while coeffs.len() > degree {
+            let lead = coeffs.pop().expect("coeffs is not empty");
+            if lead.is_zero() {
+                continue;
+            }
+            let shift = coeffs.len() - degree;
+            for (i, c) in f.coeffs[..degree].iter().enumerate() {
+                coeffs[shift + i] = sub_mod(&coeffs[shift + i], &lead.mul_mod(c, n), n);
+            }
+            quotient[shift] = lead;
+        }
+        if coeffs.is_empty() {
+            coeffs.push(T::zero());
+        }
+        (Polynomial{ coeffs: quotient }.drop_trailing_zeros(), Polynomial{ coeffs }.drop_trailing_zeros())
+    }
+
+    // The remainder of self divided by the monic polynomial f
+    pub fn rem_monic(&self, f: &Self, n: &T) -> Self {
+        self.div_rem_monic(f, n).1
+    }
+
+    pub fn mul_mod_monic(&self, other: &Self, f: &Self, n: &T) -> Self {
+        let mut coeffs = vec![T::zero(); self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, a) in self.coeffs.iter().enumerate() {
+            if a.is_zero() {
+                continue;
+            }
+            for (j, b) in other.coeffs.iter().enumerate() {
+                coeffs[i + j] = add_mod(&coeffs[i + j], &a.mul_mod(b, n), n);
+            }
+        }
+        Polynomial{ coeffs }.rem_monic(f, n)
+    }
+
+    pub fn pow_mod_monic(&self, exponent: &T, f: &Self, n: &T) -> Self {
+        let base = self.rem_monic(f, n);
+        let mut result = Polynomial{ coeffs: vec![T::one()] }.rem_monic(f, n);
+        for i in (0..exponent.bits()).rev() {
+            result = result.mul_mod_monic(&result, f, n);
+            if exponent.bit(i) {
+                result = result.mul_mod_monic(&base, f, n);
+            }
+        }
+        result
+    }
+
+    fn reduce_coefficients(&self, n: &T) -> Self {
+        Polynomial{ coeffs: self.coeffs.iter().map(|c| c.mod_floor(n)).collect() }.drop_trailing_zeros()
+    }
+
+    // self divided by its leading coefficient, or Err(gcd) with n if that is not invertible
+    pub fn monic_mod(&self, n: &T) -> Result<Self, T> {
+        let reduced = self.reduce_coefficients(n);
+        let inverse = mod_inverse(&reduced.leading_coefficient(), n)?;
+        Ok(Polynomial{ coeffs: reduced.coeffs.iter().map(|c| c.mul_mod(&inverse, n)).collect() })
+    }
+
+    // The monic gcd of self and other over Z/nZ. For composite n this can run into
+    // a leading coefficient that is not invertible, and then its gcd with n is returned.
+    pub fn gcd_mod(&self, other: &Self, n: &T) -> Result<Self, T> {
+        let mut a = self.reduce_coefficients(n);
+        let mut b = other.reduce_coefficients(n);
+        while !b.is_zero() {
+            b = b.monic_mod(n)?;
+            let remainder = a.rem_monic(&b, n);
+            a = std::mem::replace(&mut b, remainder);
+        }
+        if a.is_zero() {
+            return Ok(a);
+        }
+        a.monic_mod(n)
+    }
+
+    // The resultant of self and other over Z/nZ by the Euclidean algorithm,
+    // with Err(gcd) as for gcd_mod.
+    pub fn resultant_mod(&self, other: &Self, n: &T) -> Result<T, T> {
+        let mut a = self.reduce_coefficients(n);
+        let mut b = other.reduce_coefficients(n);
+        let mut result = T::one().mod_floor(n);
+        loop {
+            if a.is_zero() || b.is_zero() {
+                return Ok(T::zero());
+            }
+            let (degree_a, degree_b) = (a.degree(), b.degree());
+            let lead = b.leading_coefficient();
+            if degree_b == 0 {
+                for _ in 0..degree_a {
+                    result = result.mul_mod(&lead, n);
+                }
+                return Ok(result);
+            }
+            // res(a, b) = (-1)^(deg a deg b) res(b, a) and res(b, a) = lc(b)^(deg a - deg r) res(b, r)
+            let r = a.rem_monic(&b.monic_mod(n)?, n);
+            if degree_a * degree_b % 2 == 1 {
+                result = sub_mod(&T::zero(), &result, n);
+            }
+            if r.is_zero() {
+                return Ok(T::zero());
+            }
+            for _ in r.degree()..degree_a {
+                result = result.mul_mod(&lead, n);
+            }
+            a = std::mem::replace(&mut b, r);
+        }
+    }
+
+    // The discriminant (-1)^(d (d - 1) / 2) res(f, f') of a monic f of degree d, mod n
+    pub fn discriminant_mod(&self, n: &T) -> Result<T, T> {
+        let f = self.reduce_coefficients(n);
+        let degree = f.degree();
+        let derivative = Polynomial{
+            coeffs: f.coeffs.iter().enumerate().skip(1)
+                     .map(|(i, c)| T::from_usize(i).expect("degree fits into T").mod_floor(n).mul_mod(c, n))
+                     .collect()
+        };
+        let resultant = f.resultant_mod(&derivative, n)?;
+        if degree * (degree - 1) / 2 % 2 == 1 {
+            Ok(sub_mod(&T::zero(), &resultant, n))
+        } else {
+            Ok(resultant)
+        }
+    }
+}
+
 // Polynomial - Scalar operations
 impl<T> Sub<T> for Polynomial<T> where 
     T:  SubAssign + Clone + Zero + PartialEq {
@@ -112,7 +292,7 @@ impl<T> Sub<T> for Polynomial<T> where
     fn sub(self, rhs: T) -> Self::Output {
         let mut coeffs = self.coeffs.clone();
         coeffs[0] -= rhs;
-        return Polynomial{ coeffs };
+        Polynomial{ coeffs }
     }
     
 }
@@ -124,7 +304,7 @@ impl<T> Add<T> for Polynomial<T> where
     fn add(self, rhs: T) -> Self::Output {
         let mut coeffs = self.coeffs.clone();
         coeffs[0] += rhs;
-        return Polynomial{ coeffs };
+        Polynomial{ coeffs }
     }
     
 }
@@ -197,7 +377,7 @@ impl<T> Mul<Polynomial<T>> for Polynomial<T> where
                 coeffs[i + j] += self.coeffs[i].clone() * other.coeffs[j].clone();
             }
         }
-        return Polynomial{ coeffs };
+        Polynomial{ coeffs }
     }
 }
 
@@ -284,10 +464,6 @@ impl<T> PartialEq<Polynomial<T>> for Polynomial<T> where
     fn eq(&self, other: &Polynomial<T>) -> bool {
         self.drop_trailing_zeros().coeffs == other.drop_trailing_zeros().coeffs
     }
-
-    fn ne(&self, other: &Polynomial<T>) -> bool {
-        self.drop_trailing_zeros().coeffs != other.drop_trailing_zeros().coeffs
-    }
 }
 
 
@@ -1,92 +1,121 @@
-pub use crate::utils::{mod_exp, jacobi, is_perfect_power};
-pub use rand::{seq::IteratorRandom, thread_rng};
+pub use crate::utils::{Int, gcd, add_mod, sub_mod, mod_exp, jacobi, is_perfect_power, is_perfect_square};
+pub use crate::polynomial::Polynomial;
+pub use crate::montgomery::ModularContext;
+pub use rand::{Rng, thread_rng};
 pub use num::traits::Pow;
+use std::ops::{Add, AddAssign, Mul, Rem, Sub};
+use num::{One, ToPrimitive, Zero};
 
-pub enum Primality {
-    Prime,
-    Composite,
-    ProbablyPrime
+use std::fmt::{self, Display};
+
+pub mod probabilistic;
+pub mod deterministic;
+pub mod pratt;
+pub mod special;
+pub mod ecpp;
+pub mod strategy;
+pub mod generation;
+pub use pratt::PrattCertificate;
+pub use deterministic::PocklingtonCertificate;
+pub use ecpp::EcppCertificate;
+pub use strategy::{PrimalityTest, ErrorBound};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Primality<T> {
+    Prime(Option<Certificate<T>>),
+    Composite(Witness<T>),
+    // A composite passes all rounds with probability at most 2^-error_bits,
+    // error_bits is None when no such bound is known (e.g. Fermat on Carmichael numbers).
+    ProbablyPrime { rounds: usize, error_bits: Option<usize> }
 }
 
-pub mod probabilistic { 
-    use super::*;
+// Why n is not prime
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Witness<T> {
+    // n is 0 or 1
+    SmallerThanTwo,
+    // a nontrivial factor of n
+    Factor(T),
+    // a base for which n fails a Fermat, Euler or strong probable-prime test,
+    // or for which the AKS congruence does not hold
+    Base(T),
+    // n fails the strong Lucas test with Selfridge's parameter D
+    Lucas(i64),
+    PerfectPower,
+    // the nonzero final residue of a Lucas-Lehmer type test
+    Residue(T),
+    // n fails the Frobenius test for this polynomial, coefficients from the constant term up
+    Polynomial(Vec<T>),
+}
 
-    pub fn fermat_primality_test(n: u64, n_iter: usize) -> Primality {
-        let mut rng = thread_rng();
-        let v: Vec<u64> = (2..n).collect();
-        let bases = v.iter().choose_multiple(&mut rng, n_iter);
-        
-        for a in bases {
-            if mod_exp(*a, n - 1,  n) != 1 {
-                return Primality::Composite;
-            }
-        }
-        Primality::ProbablyPrime
+// Why n is prime
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Certificate<T> {
+    // n has no prime factor up to its square root
+    TrialDivision,
+    // n is below a bound up to which the strong tests to these bases are conclusive
+    StrongBases(Vec<T>),
+    Pratt(PrattCertificate<T>),
+    Pocklington(PocklingtonCertificate<T>),
+    Ecpp(EcppCertificate<T>),
+}
+
+impl<T> Primality<T> {
+    pub fn is_prime(&self) -> bool {
+        matches!(self, Primality::Prime(_))
     }
 
-    pub fn solovay_strassen_primality_test(n: u64, n_iter: usize) -> Primality {
-        if n % 2 == 0 {
-            return Primality::Composite;
-        }
-        let mut rng = thread_rng();
-        let v: Vec<u64> = (2..n).collect();
-        let bases = v.iter().choose_multiple(&mut rng, n_iter);
-        
-        for a in bases {
-            let jacobi_ = jacobi(*a, n);
-            let jacobi_symbol: u64 = match jacobi_ {
-                0 | 1 => jacobi_ as u64,
-                _ => n - 1, 
-            };
-    
-            if jacobi_symbol == 0 {
-                println!("jacobi = 0");
-                return Primality::Composite;
-            } else if mod_exp(*a, (n - 1) / 2, n) != jacobi_symbol {
-                println!("jacobi = {}", jacobi_symbol);
-                return Primality::Composite;
-            }
-        }
-        Primality::ProbablyPrime
+    pub fn is_composite(&self) -> bool {
+        matches!(self, Primality::Composite(_))
     }
 
+    pub fn is_probably_prime(&self) -> bool {
+        matches!(self, Primality::ProbablyPrime { .. })
+    }
+}
 
-    pub fn miller_rabin_primality_test(n: u64, n_iter: u64) -> Primality {
-        if n % 2 == 0 {
-            return Primality::Composite;
+impl<T: Display> Display for Primality<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Primality::Prime(None) => write!(f, "prime"),
+            Primality::Prime(Some(certificate)) => write!(f, "prime ({})", certificate),
+            Primality::Composite(witness) => write!(f, "composite ({})", witness),
+            Primality::ProbablyPrime { rounds, error_bits: Some(bits) } =>
+                write!(f, "probably prime ({} rounds, error below 2^-{})", rounds, bits),
+            Primality::ProbablyPrime { rounds, error_bits: None } =>
+                write!(f, "probably prime ({} rounds)", rounds),
         }
-    
-        let mut rng = thread_rng();
-        let v: Vec<u64> = (2..n).collect();
-        let bases = v.iter().choose_multiple(&mut rng, n_iter as usize);
-    
-        // n - 1 = 2^s * n', s > 1
-        let s: u64 = (n - 1).trailing_zeros() as u64;
-        let n_dash: u64 = (n - 1) >> s;
-    
-    
-        for a in bases {
-            let mut b = mod_exp(*a, n_dash, n);
-            let mut j: u64 = 0;
-            
-            while (j <= s - 2) && (b != n - 1) {
-                b = b * b % n;
-                if b == 1 {
-                    return Primality::Composite;
-                }
-                j +=1;
-            }
-            if b != n - 1 {
-                return Primality::Composite;
+    }
+}
+
+impl<T: Display> Display for Witness<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Witness::SmallerThanTwo => write!(f, "smaller than 2"),
+            Witness::Factor(factor) => write!(f, "divisible by {}", factor),
+            Witness::Base(base) => write!(f, "witness {}", base),
+            Witness::Lucas(d) => write!(f, "strong Lucas witness D = {}", d),
+            Witness::PerfectPower => write!(f, "perfect power"),
+            Witness::Residue(residue) => write!(f, "residue {}", residue),
+            Witness::Polynomial(coeffs) => {
+                let coeffs: Vec<String> = coeffs.iter().map(|c| c.to_string()).collect();
+                write!(f, "Frobenius witness with coefficients {}", coeffs.join(", "))
             }
         }
-        if n_iter < n {
-            return Primality::ProbablyPrime;
-        }
-        Primality::Prime
-        }
+    }
 }
 
-pub mod deterministic {
-    // TODO
-}
\ No newline at end of file
+impl<T: Display> Display for Certificate<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Certificate::TrialDivision => write!(f, "trial division"),
+            Certificate::StrongBases(bases) => {
+                let bases: Vec<String> = bases.iter().map(|a| a.to_string()).collect();
+                write!(f, "strong bases {}", bases.join(", "))
+            }
+            Certificate::Pratt(_) => write!(f, "Pratt certificate"),
+            Certificate::Pocklington(_) => write!(f, "Pocklington certificate"),
+            Certificate::Ecpp(certificate) => write!(f, "ECPP certificate with {} steps", certificate.steps.len()),
+        }
+    }
+}
@@ -0,0 +1,364 @@
+use super::*;
+use super::probabilistic::is_strong_probable_prime;
+use crate::factor::brent_rho;
+
+const SMALL_PRIMES: [u64; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41,
+    43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97
+];
+
+// (bound, bases): the strong test to every base in `bases` is correct for all n < bound.
+// Sources: Pomerance, Selfridge & Wagstaff (1980), Jaeschke (1993),
+// Jiang & Deng (2014) and Sinclair's seven-base set for the whole u64 range.
+const MILLER_RABIN_BASES: [(u64, &[u64]); 7] = [
+    (2_047, &[2]),
+    (1_373_653, &[2, 3]),
+    (25_326_001, &[2, 3, 5]),
+    (3_215_031_751, &[2, 3, 5, 7]),
+    (2_152_302_898_747, &[2, 3, 5, 7, 11]),
+    (3_474_749_660_383, &[2, 3, 5, 7, 11, 13]),
+    (341_550_071_728_321, &[2, 3, 5, 7, 11, 13, 17]),
+];
+
+const SINCLAIR_BASES: [u64; 7] = [2, 325, 9_375, 28_178, 450_775, 9_780_504, 1_795_265_022];
+
+// Deterministic Miller-Rabin: never returns Primality::ProbablyPrime.
+// `% p == 0` rather than is_multiple_of, which needs Rust 1.87.
+#[allow(clippy::manual_is_multiple_of)]
+pub fn primality_test(n: u64) -> Primality<u64> {
+    if n < 2 {
+        return Primality::Composite(Witness::SmallerThanTwo);
+    }
+    for p in SMALL_PRIMES {
+        if n == p {
+            return Primality::Prime(Some(Certificate::TrialDivision));
+        }
+        if n % p == 0 {
+            return Primality::Composite(Witness::Factor(p));
+        }
+    }
+    // No prime factor up to 97, so n is prime if n < 101^2.
+    if n < 101 * 101 {
+        return Primality::Prime(Some(Certificate::TrialDivision));
+    }
+
+    let bases = MILLER_RABIN_BASES.iter()
+                                  .find(|(bound, _)| n < *bound)
+                                  .map_or(&SINCLAIR_BASES[..], |(_, bases)| *bases);
+
+    for a in bases {
+        // A base divisible by n carries no information and is skipped.
+        let a = a % n;
+        if a != 0 && !is_strong_probable_prime(n, a) {
+            return Primality::Composite(Witness::Base(a));
+        }
+    }
+    Primality::Prime(Some(Certificate::StrongBases(bases.to_vec())))
+}
+
+pub fn is_prime(n: u64) -> bool {
+    primality_test(n).is_prime()
+}
+
+// Agrawal-Kayal-Saxena. Runs in polynomial time, but only practical for small n;
+// it is meant for teaching and for cross-checking the other tests.
+pub fn aks(n: u64) -> Primality<u64> {
+    if n < 2 {
+        return Primality::Composite(Witness::SmallerThanTwo);
+    }
+    if is_perfect_power(n) {
+        return Primality::Composite(Witness::PerfectPower);
+    }
+
+    // Smallest r with ord_r(n) > log2(n)^2
+    let log = (n as f64).log2();
+    let max_k = (log * log).floor() as u64;
+    let mut r: u64 = 2;
+    while gcd(r, n) != 1 || (1..=max_k).any(|k| mod_exp(n, k, r) == 1) {
+        r += 1;
+    }
+
+    for a in 2..=r.min(n - 1) {
+        let d = gcd(a, n);
+        if 1 < d && d < n {
+            return Primality::Composite(Witness::Factor(d));
+        }
+    }
+    if n <= r {
+        return Primality::Prime(None);
+    }
+
+    let phi = (1..r).filter(|&k| gcd(k, r) == 1).count() as f64;
+    let limit = (phi.sqrt() * log).floor() as u64;
+
+    let fits_u64 = ((n - 1) as u128).pow(2).checked_mul(r as u128)
+                                              .is_some_and(|bound| bound <= u64::MAX as u128);
+    let failing_base = if fits_u64 {
+        aks_congruences::<u64>(n, r as usize, limit)
+    } else {
+        assert!((n - 1) as u128 <= (u128::MAX / r as u128).isqrt(),
+                "n is too large for AKS");
+        aks_congruences::<u128>(n as u128, r as usize, limit)
+    };
+    match failing_base {
+        Some(a) => Primality::Composite(Witness::Base(a)),
+        None => Primality::Prime(None),
+    }
+}
+
+// The first 1 <= a <= limit for which (x + a)^n = x^(n mod r) + a
+// does not hold in (Z/nZ)[x] / (x^r - 1), if any.
+fn aks_congruences<T>(n: T, r: usize, limit: u64) -> Option<u64> where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Rem<Output = T> + AddAssign
+       + Clone + Zero + One + PartialEq + From<u64> + ToPrimitive {
+    let exponent = n.to_u64().expect("n fits into u64");
+    let shift = (exponent % r as u64) as usize;
+
+    for base in 1..=limit {
+        let a = T::from(base) % n.clone();
+        let lhs = Polynomial{ coeffs: vec![a.clone(), T::one()] }.pow_mod(exponent, r, &n);
+
+        let mut x_n = vec![T::zero(); shift + 1];
+        x_n[shift] = T::one();
+        let rhs = (Polynomial{ coeffs: x_n } + a).reduce_mod(r, &n);
+
+        if lhs != rhs {
+            return Some(base);
+        }
+    }
+    None
+}
+
+// Trial division bound when factoring n - 1 for the Pocklington prover
+const TRIAL_DIVISION_BOUND: u64 = 1 << 16;
+// Steps of Pollard's rho per polynomial before a cofactor is left unfactored
+const RHO_ITERATIONS: u64 = 1 << 15;
+// Bases tried for each prime factor of F before giving up
+const POCKLINGTON_BASES: u64 = 1000;
+
+// A proof that n is prime from a partial factorization n - 1 = F * R, where F is
+// the product of the listed prime powers. Every prime factor p of n is then 1 mod F,
+// which is enough when F^2 >= n (Pocklington-Lehmer). When only F^3 >= n,
+// writing n = c2 * F^2 + c1 * F + 1, n is prime iff c1^2 - 4 * c2 is not a square
+// (Brillhart-Lehmer-Selfridge).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PocklingtonCertificate<T> {
+    pub n: T,
+    pub factors: Vec<PocklingtonFactor<T>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PocklingtonFactor<T> {
+    pub q: T,
+    pub exponent: u32,
+    // a base with a^(n - 1) = 1 and gcd(a^((n - 1) / q) - 1, n) = 1
+    pub base: T,
+    // why q is prime, None when q fits into u64 and primality_test settles it
+    pub certificate: Option<Box<PocklingtonCertificate<T>>>,
+}
+
+impl<T: Int> PocklingtonCertificate<T> {
+    // Checks the whole certificate, trusting nothing but modular arithmetic
+    // and the deterministic test for factors below 2^64.
+    pub fn verify(&self) -> bool {
+        let one = T::one();
+        let two = one.clone() + one.clone();
+        if self.n == two {
+            return self.factors.is_empty();
+        }
+        if self.n < two || self.n.is_even() {
+            return false;
+        }
+
+        let n_minus_one = self.n.clone() - one.clone();
+        let mut f = one.clone();
+        for factor in &self.factors {
+            let q_is_prime = match &factor.certificate {
+                Some(certificate) => certificate.n == factor.q && certificate.verify(),
+                None => factor.q.to_u64().is_some_and(is_prime),
+            };
+            if factor.exponent == 0 || !q_is_prime {
+                return false;
+            }
+            for _ in 0..factor.exponent {
+                f = f * factor.q.clone();
+                if !n_minus_one.is_multiple_of(&f) {
+                    return false;
+                }
+            }
+
+            let a = factor.base.clone();
+            if a <= one || a >= self.n || !mod_exp(a.clone(), n_minus_one.clone(), self.n.clone()).is_one() {
+                return false;
+            }
+            let power = mod_exp(a, n_minus_one.clone() / factor.q.clone(), self.n.clone());
+            if !gcd(sub_mod(&power, &one, &self.n), self.n.clone()).is_one() {
+                return false;
+            }
+        }
+
+        // n - 1 = F * R, and F^2 >= n iff R < F.
+        let r = n_minus_one / f.clone();
+        if r < f {
+            return true;
+        }
+        let (c2, c1) = r.div_rem(&f);
+        if c2 >= f {
+            return false;
+        }
+        let four = two.clone() + two;
+        let c1_squared = c1.clone() * c1;
+        let c2_times_four = four * c2;
+        c1_squared < c2_times_four || !is_perfect_square(&(c1_squared - c2_times_four))
+    }
+}
+
+// Pocklington-Lehmer, or Brillhart-Lehmer-Selfridge when n - 1 is only factored
+// beyond the cube root of n. Composites are caught by BPSW first; a prime whose
+// n - 1 cannot be factored far enough stays ProbablyPrime.
+pub fn pocklington_primality_test<T: Int>(n: T) -> Primality<T> {
+    let verdict = probabilistic::bpsw_primality_test(n.clone());
+    if verdict.is_composite() {
+        return verdict;
+    }
+    match pocklington_proof(n) {
+        Ok(certificate) => Primality::Prime(Some(Certificate::Pocklington(certificate))),
+        Err(Some(witness)) => Primality::Composite(witness),
+        Err(None) => verdict,
+    }
+}
+
+// Builds a certificate for n, None if n is not prime or n - 1 could not be factored far enough.
+pub fn pocklington_certificate<T: Int>(n: T) -> Option<PocklingtonCertificate<T>> {
+    match pocklington_primality_test(n) {
+        Primality::Prime(Some(Certificate::Pocklington(certificate))) => Some(certificate),
+        _ => None,
+    }
+}
+
+// Err(Some(witness)) if n turned out to be composite, Err(None) if there is no proof either way.
+fn pocklington_proof<T: Int>(n: T) -> Result<PocklingtonCertificate<T>, Option<Witness<T>>> {
+    let one = T::one();
+    let two = one.clone() + one.clone();
+    if n == two {
+        return Ok(PocklingtonCertificate{ n, factors: Vec::new() });
+    }
+    let n_minus_one = n.clone() - one.clone();
+
+    let (probable_primes, mut r) = partial_factorization(n_minus_one.clone());
+    let mut factors = Vec::new();
+    for (q, exponent) in probable_primes {
+        let certificate = if q.to_u64().is_some() {
+            None
+        } else {
+            match pocklington_proof(q.clone()) {
+                Ok(certificate) => Some(Box::new(certificate)),
+                Err(_) => {
+                    for _ in 0..exponent {
+                        r = r * q.clone();
+                    }
+                    continue;
+                }
+            }
+        };
+        factors.push(PocklingtonFactor{ q, exponent, base: one.clone(), certificate });
+    }
+
+    let f = n_minus_one.clone() / r.clone();
+    let (c2, c1) = r.div_rem(&f);
+    if r >= f && c2 >= f {
+        return Err(None);
+    }
+
+    for factor in &mut factors {
+        let exponent = n_minus_one.clone() / factor.q.clone();
+        let mut found = false;
+        for a in (2..POCKLINGTON_BASES).map_while(T::from_u64).take_while(|a| *a < n) {
+            if !mod_exp(a.clone(), n_minus_one.clone(), n.clone()).is_one() {
+                return Err(Some(Witness::Base(a)));
+            }
+            let power = mod_exp(a.clone(), exponent.clone(), n.clone());
+            let d = gcd(sub_mod(&power, &one, &n), n.clone());
+            if d.is_one() {
+                factor.base = a;
+                found = true;
+                break;
+            }
+            if d != n {
+                return Err(Some(Witness::Factor(d)));
+            }
+        }
+        if !found {
+            return Err(None);
+        }
+    }
+
+    if r >= f {
+        // Every prime factor of n is 1 mod F, so a composite n is (a F + 1)(b F + 1)
+        // with a + b = c1 and a b = c2, and the discriminant is a square.
+        let four = two.clone() + two.clone();
+        let c1_squared = c1.clone() * c1.clone();
+        let c2_times_four = four * c2;
+        if c1_squared >= c2_times_four {
+            let discriminant = c1_squared - c2_times_four;
+            if is_perfect_square(&discriminant) {
+                let a = (c1 + discriminant.sqrt()) / two;
+                return Err(Some(Witness::Factor(a * f + one)));
+            }
+        }
+    }
+    Ok(PocklingtonCertificate{ n, factors })
+}
+
+// Splits m into probable prime powers, sorted by prime, and a cofactor that
+// neither trial division nor Pollard's rho could break up.
+fn partial_factorization<T: Int>(mut m: T) -> (Vec<(T, u32)>, T) {
+    let mut primes: Vec<T> = Vec::new();
+    let mut d: u64 = 2;
+    while d <= TRIAL_DIVISION_BOUND {
+        let divisor = T::from_u64(d).expect("trial divisor fits into T");
+        if divisor.clone() * divisor.clone() > m {
+            break;
+        }
+        while m.is_multiple_of(&divisor) {
+            primes.push(divisor.clone());
+            m = m / divisor.clone();
+        }
+        d += if d == 2 { 1 } else { 2 };
+    }
+
+    let mut cofactor = T::one();
+    let mut stack = vec![m];
+    while let Some(m) = stack.pop() {
+        if m.is_one() {
+            continue;
+        }
+        if !probabilistic::bpsw_primality_test(m.clone()).is_composite() {
+            primes.push(m);
+            continue;
+        }
+        match pollard_rho(&m) {
+            Some(d) => {
+                stack.push(m.clone() / d.clone());
+                stack.push(d);
+            }
+            None => cofactor = cofactor * m,
+        }
+    }
+    primes.sort();
+
+    let mut factors: Vec<(T, u32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((q, e)) if *q == p => *e += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    (factors, cofactor)
+}
+
+// A nontrivial factor of a composite n without small prime factors, if
+// Brent's rho on x -> x^2 + c finds one within RHO_ITERATIONS steps for some small c.
+pub(super) fn pollard_rho<T: Int>(n: &T) -> Option<T> {
+    (1..=3).find_map(|c| brent_rho(n, &T::from_u64(c).expect("constant fits into T"), RHO_ITERATIONS))
+}
@@ -0,0 +1,323 @@
+// Elliptic curve primality proving with Atkin-Morain certificates. Each step shows
+// that n is prime if q is: a point P on E: y^2 = x^3 + a x + b over Z/nZ with m P = O
+// and (m / q) P != O, for a prime q > (n^(1/4) + 1)^2, is enough (Goldwasser-Kilian).
+// The curves come from complex multiplication: for a discriminant D with 4n = u^2 + |D| v^2,
+// a root of the Hilbert class polynomial H_D mod n is the j-invariant of a curve of order
+// n + 1 - u or n + 1 + u, with more choices for D = -3, -4.
+use num::{BigInt, Integer};
+
+use super::*;
+use super::deterministic::{is_prime, pollard_rho};
+use crate::elliptic_curve::{Curve, Point};
+use crate::sieve::primes_up_to;
+use crate::utils::{mod_inverse, sqrt_mod};
+
+mod class_polynomials;
+use class_polynomials::CLASS_POLYNOMIALS;
+
+// Bound for the small prime factors divided out of the candidate orders
+const TRIAL_DIVISION_BOUND: u64 = 1 << 16;
+// Number of primes the backtracking search visits before giving up
+const MAX_NODES: usize = 1000;
+// Fewer candidates than this at a node bring in Pollard's rho on the orders
+const MIN_CANDIDATES: usize = 3;
+// Curves tried for j = 0 and j = 1728, where the right twist is found by trial
+const TWISTS: u64 = 50;
+// Points tried on each curve
+const POINTS: u64 = 20;
+
+// One link of the chain: E: y^2 = x^3 + a x + b over Z/nZ, a point on it,
+// a multiple of its order and the prime q dividing that multiple.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EcppStep<T> {
+    pub n: T,
+    pub a: T,
+    pub b: T,
+    pub point: (T, T),
+    pub order: T,
+    pub q: T,
+}
+
+// The steps take n down to a prime below 2^64, which primality_test settles.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EcppCertificate<T> {
+    pub n: T,
+    pub steps: Vec<EcppStep<T>>,
+}
+
+// Least q that the Goldwasser-Kilian theorem accepts for n, (floor(n^(1/4)) + 2)^2
+// being just above (n^(1/4) + 1)^2
+fn lower_bound<T: Int>(n: &T) -> T {
+    let root = n.nth_root(4) + T::one() + T::one();
+    root.clone() * root
+}
+
+impl<T: Int> EcppStep<T> {
+    pub fn verify(&self) -> bool {
+        let n = &self.n;
+        let six = T::from_u8(6).expect("6 fits into T");
+        if *n <= six || !gcd(n.clone(), six).is_one() || self.a >= *n || self.b >= *n {
+            return false;
+        }
+        let curve = Curve{ a: self.a.clone(), b: self.b.clone(), n: n.clone() };
+        let point = Point::Affine(self.point.0.clone(), self.point.1.clone());
+        if !curve.is_nonsingular() || !curve.contains(&point) {
+            return false;
+        }
+        if self.q < lower_bound(n) || !self.order.is_multiple_of(&self.q) {
+            return false;
+        }
+
+        match curve.mul(&point, &(self.order.clone() / self.q.clone())) {
+            Ok(Point::Affine(x, y)) => curve.mul(&Point::Affine(x, y), &self.q) == Ok(Point::Infinity),
+            _ => false,
+        }
+    }
+}
+
+impl<T: Int> EcppCertificate<T> {
+    // Checks every step and the chain of primes, trusting nothing but modular arithmetic
+    // and the deterministic test for the last prime.
+    pub fn verify(&self) -> bool {
+        let mut n = self.n.clone();
+        for step in &self.steps {
+            if step.n != n || !step.verify() {
+                return false;
+            }
+            n = step.q.clone();
+        }
+        n.to_u64().is_some_and(is_prime)
+    }
+}
+
+// Composites are caught by BPSW first; a prime for which the search runs out of
+// discriminants or nodes stays ProbablyPrime.
+pub fn ecpp_primality_test(n: BigInt) -> Primality<BigInt> {
+    let verdict = probabilistic::bpsw_primality_test(n.clone());
+    if verdict.is_composite() {
+        return verdict;
+    }
+    let primes = primes_up_to(TRIAL_DIVISION_BOUND);
+    let mut nodes = 0;
+    match descend(&n, &primes, &mut nodes) {
+        Ok(steps) => Primality::Prime(Some(Certificate::Ecpp(EcppCertificate{ n, steps }))),
+        Err(Some(witness)) => Primality::Composite(witness),
+        Err(None) => verdict,
+    }
+}
+
+// Builds a certificate for n, None if n is not prime or the search gave up.
+pub fn ecpp_certificate(n: BigInt) -> Option<EcppCertificate<BigInt>> {
+    match ecpp_primality_test(n) {
+        Primality::Prime(Some(Certificate::Ecpp(certificate))) => Some(certificate),
+        _ => None,
+    }
+}
+
+// Depth first search for a chain of steps from the probable prime n down to 2^64,
+// backing up when some q has no usable discriminant.
+fn descend(n: &BigInt, primes: &[u64], nodes: &mut usize) -> Result<Vec<EcppStep<BigInt>>, Option<Witness<BigInt>>> {
+    if n.to_u64().is_some() {
+        return if n.to_u64().is_some_and(is_prime) { Ok(Vec::new()) } else { Err(None) };
+    }
+    *nodes += 1;
+    if *nodes > MAX_NODES {
+        return Err(None);
+    }
+
+    // Smaller q first, the chain gets shorter that way.
+    let mut candidates = candidate_orders(n, primes);
+    candidates.sort_by(|x, y| x.q.cmp(&y.q));
+    for candidate in candidates {
+        if *nodes > MAX_NODES {
+            break;
+        }
+        if let Ok(mut steps) = descend(&candidate.q, primes, nodes) {
+            match construct_step(n, &candidate) {
+                Ok(step) => {
+                    steps.insert(0, step);
+                    return Ok(steps);
+                }
+                Err(Some(witness)) => return Err(Some(witness)),
+                Err(None) => continue,
+            }
+        }
+    }
+    Err(None)
+}
+
+// A possible curve order m = k q for n, with the class polynomial it comes from
+struct Candidate {
+    discriminant: usize,
+    order: BigInt,
+    q: BigInt,
+}
+
+// The orders with a probable prime cofactor q, lower_bound(n) <= q < n,
+// after dividing out the small primes. When that leaves fewer than MIN_CANDIDATES,
+// Pollard's rho is tried on the composite cofactors as well.
+fn candidate_orders(n: &BigInt, primes: &[u64]) -> Vec<Candidate> {
+    let bound = lower_bound(n);
+    let is_candidate = |q: &BigInt| *q >= bound && q < n && !probabilistic::bpsw_primality_test(q.clone()).is_composite();
+    let mut candidates = Vec::new();
+    let mut composite = Vec::new();
+    for (discriminant, (d, _)) in CLASS_POLYNOMIALS.iter().enumerate() {
+        if jacobi(BigInt::from(*d), n.clone()) != 1 {
+            continue;
+        }
+        let Some((u, v)) = cornacchia(*d, n) else {
+            continue;
+        };
+        let n_plus_one = n + 1u8;
+        let mut traces = vec![u.clone()];
+        if *d == -3 {
+            traces.push((&u + &v * 3u8) / 2u8);
+            traces.push((&u - &v * 3u8) / 2u8);
+        } else if *d == -4 {
+            traces.push(&v * 2u8);
+        }
+
+        for order in traces.iter().flat_map(|t| [&n_plus_one - t, &n_plus_one + t]) {
+            let mut q = order.clone();
+            for &p in primes {
+                while (&q % p).is_zero() {
+                    q /= p;
+                }
+            }
+            if is_candidate(&q) {
+                candidates.push(Candidate{ discriminant, order, q });
+            } else if q >= bound {
+                composite.push(Candidate{ discriminant, order, q });
+            }
+        }
+    }
+
+    if candidates.len() < MIN_CANDIDATES {
+        for mut candidate in composite {
+            while let Some(d) = pollard_rho(&candidate.q) {
+                // Keep the larger part, it may be the prime.
+                let rest = &candidate.q / &d;
+                candidate.q = d.max(rest);
+                if is_candidate(&candidate.q) {
+                    candidates.push(candidate);
+                    break;
+                }
+                if candidate.q < bound {
+                    break;
+                }
+            }
+        }
+    }
+    candidates
+}
+
+// (u, v) with u^2 + |d| v^2 = 4n for a prime n, if there is a solution
+// (Cornacchia's algorithm, in the form for 4n).
+fn cornacchia(d: i64, n: &BigInt) -> Option<(BigInt, BigInt)> {
+    let mut x = sqrt_mod(BigInt::from(d), n.clone())?;
+    if x.is_odd() != (d % 2 != 0) {
+        x = n - x;
+    }
+    let four_n: BigInt = n * 4u8;
+    let limit = four_n.sqrt();
+    let (mut a, mut b) = (n * 2u8, x);
+    while b > limit {
+        let r = &a % &b;
+        a = std::mem::replace(&mut b, r);
+    }
+    let rest = four_n - &b * &b;
+    let abs_d = BigInt::from(-d);
+    if !rest.is_multiple_of(&abs_d) {
+        return None;
+    }
+    let c = rest / abs_d;
+    if !is_perfect_square(&c) {
+        return None;
+    }
+    Some((b, c.sqrt()))
+}
+
+// A root of H_D mod the prime n: the roots of gcd(x^n - x, H_D) are split apart
+// by gcd((x + delta)^((n - 1) / 2) - 1, .) for delta = 0, 1, ...
+fn class_polynomial_root(coefficients: &[&str], n: &BigInt) -> Result<Option<BigInt>, BigInt> {
+    let coeffs = coefficients.iter()
+                             .map(|c| c.parse::<BigInt>().expect("class polynomial coefficient").mod_floor(n))
+                             .collect();
+    let f = Polynomial{ coeffs };
+    let x = Polynomial{ coeffs: vec![BigInt::zero(), BigInt::one()] };
+
+    let x_to_n = x.pow_mod_monic(n, &f, n);
+    let mut g = f.gcd_mod(&(x_to_n - x.clone()), n)?;
+    if g.degree() == 0 {
+        return Ok(None);
+    }
+    let exponent: BigInt = (n - 1u8) / 2u8;
+    let mut delta = BigInt::zero();
+    while g.degree() > 1 {
+        let power = (x.clone() + delta.clone()).pow_mod_monic(&exponent, &g, n);
+        let h = g.gcd_mod(&(power - BigInt::one()), n)?;
+        if 0 < h.degree() && h.degree() < g.degree() {
+            g = h;
+        }
+        delta += 1;
+    }
+    Ok(Some((n - &g.coeffs[0]).mod_floor(n)))
+}
+
+// The curves of which one should have the candidate's order: for j = 0 and j = 1728
+// a batch of y^2 = x^3 + b and y^2 = x^3 + a x, otherwise y^2 = x^3 - 3c x + 2c
+// with c = j / (j - 1728) and its quadratic twist.
+fn candidate_curves(n: &BigInt, d: i64, coefficients: &[&str]) -> Result<Vec<(BigInt, BigInt)>, BigInt> {
+    if d == -3 {
+        return Ok((1..=TWISTS).map(|b| (BigInt::zero(), BigInt::from(b))).collect());
+    }
+    if d == -4 {
+        return Ok((1..=TWISTS).map(|a| (BigInt::from(a), BigInt::zero())).collect());
+    }
+    let Some(j) = class_polynomial_root(coefficients, n)? else {
+        return Ok(Vec::new());
+    };
+    let denominator: BigInt = (j.clone() - 1728u16).mod_floor(n);
+    let c = j * mod_inverse(&denominator, n)? % n;
+    let (a, b) = ((&c * -3i8).mod_floor(n), (&c * 2u8).mod_floor(n));
+
+    let g = (2u32..).map(BigInt::from)
+                    .find(|g| jacobi(g.clone(), n.clone()) == -1)
+                    .expect("a prime n has a quadratic non-residue");
+    let g_squared = &g * &g;
+    let twist = ((&a * &g_squared).mod_floor(n), (&b * &g_squared * &g).mod_floor(n));
+    Ok(vec![(a, b), twist])
+}
+
+// Finds a curve and a point for the candidate, Err(None) if none of the curves works.
+fn construct_step(n: &BigInt, candidate: &Candidate) -> Result<EcppStep<BigInt>, Option<Witness<BigInt>>> {
+    let factor = |d: BigInt| Some(Witness::Factor(d));
+    let (d, coefficients) = CLASS_POLYNOMIALS[candidate.discriminant];
+    let k = &candidate.order / &candidate.q;
+
+    for (a, b) in candidate_curves(n, d, coefficients).map_err(factor)? {
+        let curve = Curve::new(a, b, n.clone());
+        if !curve.is_nonsingular() {
+            continue;
+        }
+        for x in 0..POINTS {
+            let Some(point) = curve.lift_x(BigInt::from(x)) else {
+                continue;
+            };
+            let multiple = curve.mul(&point, &k).map_err(factor)?;
+            if multiple == Point::Infinity {
+                continue;
+            }
+            if curve.mul(&multiple, &candidate.q).map_err(factor)? != Point::Infinity {
+                // wrong twist
+                break;
+            }
+            let Point::Affine(x, y) = point else {
+                unreachable!()
+            };
+            return Ok(EcppStep{ n: n.clone(), a: curve.a, b: curve.b, point: (x, y),
+                                order: candidate.order.clone(), q: candidate.q.clone() });
+        }
+    }
+    Err(None)
+}
@@ -0,0 +1,1828 @@
+// Hilbert class polynomials H_D(x) for the discriminants -5000 < D < 0 of class number
+// at most 6, ordered by class number, coefficients from the constant term up.
+// H_D is the product of x - j((-b + sqrt(D)) / 2a) over the reduced forms (a, b, c)
+// of discriminant D, computed numerically and rounded.
+pub(super) const CLASS_POLYNOMIALS: [(i64, &[&str]); 281] = [
+    (-3, &["0", "1"]),
+    (-4, &["-1728", "1"]),
+    (-7, &["3375", "1"]),
+    (-8, &["-8000", "1"]),
+    (-11, &["32768", "1"]),
+    (-12, &["-54000", "1"]),
+    (-16, &["-287496", "1"]),
+    (-19, &["884736", "1"]),
+    (-27, &["12288000", "1"]),
+    (-28, &["-16581375", "1"]),
+    (-43, &["884736000", "1"]),
+    (-67, &["147197952000", "1"]),
+    (-163, &["262537412640768000", "1"]),
+    (-15, &["-121287375", "191025", "1"]),
+    (-20, &["-681472000", "-1264000", "1"]),
+    (-24, &["14670139392", "-4834944", "1"]),
+    (-32, &["12167000000", "-52250000", "1"]),
+    (-35, &["-134217728000", "117964800", "1"]),
+    (-36, &["-1790957481984", "-153542016", "1"]),
+    (-40, &["9103145472000", "-425692800", "1"]),
+    (-48, &["6549518250000", "-2835810000", "1"]),
+    (-51, &["6262062317568", "5541101568", "1"]),
+    (-52, &["-567663552000000", "-6896880000", "1"]),
+    (-60, &["153173312762625", "-37018076625", "1"]),
+    (-64, &["-7367066619912", "-82226316240", "1"]),
+    (-72, &["232381513792000000", "-377674768000", "1"]),
+    (-75, &["5209253090426880", "654403829760", "1"]),
+    (-88, &["15798135578688000000", "-6294842640000", "1"]),
+    (-91, &["-3845689020776448", "10359073013760", "1"]),
+    (-99, &["-56171326053810176", "37616060956672", "1"]),
+    (-100, &["-292143758886942437376", "-44031499226496", "1"]),
+    (-112, &["1337635747140890625", "-274917323970000", "1"]),
+    (-115, &["130231327260672000", "427864611225600", "1"]),
+    (-123, &["148809594175488000000", "1354146840576000", "1"]),
+    (-147, &["11356800389480448000000", "34848505552896000", "1"]),
+    (-148, &["-7898242515936467904000000", "-39660183801072000", "1"]),
+    (-187, &["-3845689020776448000000", "4545336381788160000", "1"]),
+    (-232, &["14871070713157137145512000000000", "-604729957849891344000", "1"]),
+    (-235, &["11946621170462723407872000", "823177419449425920000", "1"]),
+    (-267, &["531429662672621376897024000000", "19683091854079488000000", "1"]),
+    (-403, &["-108844203402491055833088000000", "2452811389229331391979520000", "1"]),
+    (-427, &["155041756222618916546936832000000", "15611455512523783919812608000", "1"]),
+    (-23, &["12771880859375", "-5151296875", "3491750", "1"]),
+    (-31, &["1566028350940383", "-58682638134", "39491307", "1"]),
+    (-44, &["-653249011576832", "270413882112", "-1122662608", "1"]),
+    (-59, &["374643194001883136", "-140811576541184", "30197678080", "1"]),
+    (-76, &["-827237892283232256", "1128678666363648", "-784074438864", "1"]),
+    (-83, &["549755813888000000000", "-41490055168000000", "2691907584000", "1"]),
+    (-92, &["-6267542200571287109375", "-263033266852296875", "-12207823849750", "1"]),
+    (-107, &["337618789203968000000000", "-6764523159552000000", "129783279616000", "1"]),
+    (-108, &["-1879994705688000000000", "224179462188000000", "-151013228706000", "1"]),
+    (-124, &["-599530686551745232383", "-874125972104525910", "-1559739536377947", "1"]),
+    (-139, &["67408489017571610198016", "-53041786755137667072", "12183160834031616", "1"]),
+    (-172, &["-692660810326239000000000000", "1164707517403692000000", "-782759106183330000", "1"]),
+    (-211, &["5310823021408898698117644288", "277390576406111100862464", "65873587288630099968", "1"]),
+    (-243, &["3338586724673519616000000000", "-3750657365033091072000000", "1855762905734664192000", "1"]),
+    (-268, &[
+        "-3189376432736929569384216000000000",
+        "32240842762858236972000000",
+        "-21667237292024856738000",
+        "1",
+    ]),
+    (-283, &["201371843156955365376000000000", "90839236535446929408000000", "89611323386832801792000", "1"]),
+    (-307, &[
+        "8987619631060626702336000000000",
+        "-5083646425734146162688000000",
+        "805016812009981390848000",
+        "1",
+    ]),
+    (-331, &[
+        "56176242840389398230218488594563072",
+        "368729929041040103875232661504",
+        "6647404730173793386463232",
+        "1",
+    ]),
+    (-379, &[
+        "15443600047689011948024601807415148544",
+        "-121567791009880876719538528321536",
+        "364395404104624239018246144",
+        "1",
+    ]),
+    (-499, &[
+        "4671133182399954782798673154437441310949376",
+        "-6063717825494266394722392560011051008",
+        "3005101108071026200706725969920",
+        "1",
+    ]),
+    (-547, &[
+        "83303937570678403968635240448000000000",
+        "-139712328431787827943469744128000000",
+        "81297395539631654721637478400000",
+        "1",
+    ]),
+    (-643, &[
+        "308052554652302847380880841299197952000000000",
+        "-6300378505047247876499651797450752000000",
+        "39545575162726134099492467011584000",
+        "1",
+    ]),
+    (-652, &[
+        "-18095625621665522953693950872675200892692248000000000",
+        "102561728837719322645921325412908000000",
+        "-68925893036109279891085639286946000",
+        "1",
+    ]),
+    (-883, &[
+        "167990285381627318187575520800123387904000000000",
+        "-151960111125245282033875619529124478976000000",
+        "34903934341011819039224295011933392896000",
+        "1",
+    ]),
+    (-907, &[
+        "149161274746524841328545894969274007552000000000",
+        "39181594208014819617565811575376314368000000",
+        "123072080721198402394477590506838687744000",
+        "1",
+    ]),
+    (-39, &["20919104368024767633", "109873509788637459", "-429878960946", "331531596", "1"]),
+    (-55, &["-18577989025032784359375", "172576736359017890625", "-20948398473375", "13136684625", "1"]),
+    (-56, &["10064086044321563803648", "2257767342088912896", "2059647197077504", "-16220384512", "1"]),
+    (-63, &["-6256903954262253662109375", "4558451243295023437500", "-193068841781250", "67515199875", "1"]),
+    (-68, &[
+        "-2089297506304000000000000",
+        "-318507038720000000000",
+        "-75843692160000000",
+        "-178211040000",
+        "1",
+    ]),
+    (-80, &[
+        "422286883970526784000000",
+        "-171263969177632000000",
+        "-13028555239824000",
+        "-1597177172000",
+        "1",
+    ]),
+    (-84, &[
+        "-5133201653210986057826304",
+        "88821246589810089394176",
+        "-5663679223085309952",
+        "-3196800946944",
+        "1",
+    ]),
+    (-96, &[
+        "-984163224549635621646336",
+        "447805364111967209472",
+        "670421055192156288",
+        "-23340144296736",
+        "1",
+    ]),
+    (-120, &[
+        "4934510722321469030006784000000",
+        "-2588458316335175909376000000",
+        "26329406807264910336000",
+        "-883067971104000",
+        "1",
+    ]),
+    (-128, &[
+        "-345363656226658026765625000000",
+        "-55499520947716391500000000",
+        "-395258439243352250000",
+        "-2729960418308000",
+        "1",
+    ]),
+    (-132, &[
+        "1656636925108948992000000000000",
+        "54984539729717250048000000000",
+        "-325211610485778048000000",
+        "-4736863498464000",
+        "1",
+    ]),
+    (-136, &[
+        "2422829169428572504087521656832",
+        "-1834607111282472051029311488",
+        "735960027609078992953344",
+        "-8151279336430848",
+        "1",
+    ]),
+    (-144, &[
+        "571751321233328637579104256",
+        "490453856866850787293184",
+        "269499185406087942528",
+        "-23578503968570400",
+        "1",
+    ]),
+    (-155, &[
+        "37425860028464856284790784000000",
+        "20396251654725321097216000000",
+        "-44477871096357453824000",
+        "96905542950912000",
+        "1",
+    ]),
+    (-156, &[
+        "421266000645144840703921125633",
+        "-164993592496972989327022035",
+        "53074935443801207676942",
+        "-109914552886955148",
+        "1",
+    ]),
+    (-160, &[
+        "-293835053960432980416000000",
+        "13208221536779701382400000",
+        "-2940735389875294896000",
+        "-181195519824640800",
+        "1",
+    ]),
+    (-168, &[
+        "496644064976895846912000000000000000",
+        "-264691184105480095991808000000000",
+        "336511679671210230144000000",
+        "-483435712076832000",
+        "1",
+    ]),
+    (-171, &[
+        "-1311901521779155773721411584",
+        "8391550371275812148084736",
+        "472103267541360574464",
+        "694282057876537344",
+        "1",
+    ]),
+    (-180, &[
+        "-6776421923145961044033929216000000",
+        "16660473763558887652278272000000",
+        "-2867757758882006477169664000",
+        "-2018504138609120000",
+        "1",
+    ]),
+    (-184, &[
+        "114574710497270997578522590458150912",
+        "38705419208160503264676104110080",
+        "5767007465145198439020847104",
+        "-3215890895076912384",
+        "1",
+    ]),
+    (-192, &[
+        "-1080060886113159937649308593750000",
+        "826335556188178615474500000000",
+        "15705521635909735050750000",
+        "-8041801037378436000",
+        "1",
+    ]),
+    (-195, &[
+        "-233490285492432753672585216000000",
+        "104773100319600336175104000000",
+        "25349140792043819237376000",
+        "11284411506057216000",
+        "1",
+    ]),
+    (-196, &[
+        "-2108010653658430719613224868701536256",
+        "250850701957837760512539510177792",
+        "-44864481851299856707307347968",
+        "-12626092121367165696",
+        "1",
+    ]),
+    (-203, &[
+        "31913605837856413057024000000000000",
+        "250634002097696556449792000000000",
+        "-83053272156952592384000000",
+        "27502410406723584000",
+        "1",
+    ]),
+    (-208, &[
+        "1463592841477827633000000000000",
+        "-3908668494888708948000000000",
+        "4032372412181255526000000",
+        "-47568078792050004000",
+        "1",
+    ]),
+    (-219, &[
+        "110979720274963942538198675506593792",
+        "-15979705448736682450562851012608",
+        "831039118453558669939310592",
+        "155212323706544357376",
+        "1",
+    ]),
+    (-220, &[
+        "4189527305843979870968496890625",
+        "-6015443509589489085440390625",
+        "2531540097646020954716625",
+        "-172572544407169076625",
+        "1",
+    ]),
+    (-228, &[
+        "120020259495560805847424176128000000000000",
+        "58827548670433207062445836288000000000",
+        "-7985216535621460489954944000000",
+        "-399605224650084576000",
+        "1",
+    ]),
+    (-240, &[
+        "51848746810441819437662737568196890625",
+        "-3213137488352330508627918491550000",
+        "15510636623637225985530243375",
+        "-1370337635584848362400",
+        "1",
+    ]),
+    (-252, &[
+        "398963060554172791168212890625",
+        "-2443574658947106282398437500",
+        "10887176246183122533468750",
+        "-4558302700896532039875",
+        "1",
+    ]),
+    (-256, &[
+        "-1064410681181869521037208505239142408",
+        "26925623396663008311375890966784",
+        "-1826592673506207200904172752",
+        "-6761166974781862161312",
+        "1",
+    ]),
+    (-259, &[
+        "4384296738486457527093398159228928",
+        "5493320206929896679139197321216",
+        "-368189472100537894019530752",
+        "9068999694311625523200",
+        "1",
+    ]),
+    (-275, &[
+        "-3984711300201636241319486354007863066624",
+        "51213041627075282291106746090041376768",
+        "-1470671864720383632491493195776",
+        "42230108051959368384512",
+        "1",
+    ]),
+    (-280, &[
+        "1775168961518724506399346503073398784000000",
+        "-708555761206745670461365038563328000000",
+        "17602516524144666384420962098176000",
+        "-67667966893419063840000",
+        "1",
+    ]),
+    (-288, &[
+        "40994594700208456153393000000000000",
+        "136478143044657426076564000000000",
+        "-87330008255955399131086000000",
+        "-142637765058468510772000",
+        "1",
+    ]),
+    (-291, &[
+        "21782000952710117887925312635418808680448",
+        "285389231946718842181542553187254272",
+        "10786588141336392324590050738176",
+        "188155567079341753466880",
+        "1",
+    ]),
+    (-292, &[
+        "-380259461042512404779990642688000000000000",
+        "45521551386379385369629968384000000000",
+        "-93693622511929038759497066112000000",
+        "-206287709860428304608000",
+        "1",
+    ]),
+    (-312, &[
+        "1698899690981885675579246225669492736000000000000",
+        "-152340504750882110373595179663329280000000000",
+        "1411168483733488619338991640960000000",
+        "-1258031100283439093280000",
+        "1",
+    ]),
+    (-315, &[
+        "16319518649540946810407747584000000",
+        "256755031834901461034493542400000",
+        "116880346596078822090604544000",
+        "1641640623633325773619200",
+        "1",
+    ]),
+    (-323, &[
+        "-121974636783103604190112617857024000000000000",
+        "73804562114102168041788801024000000000000",
+        "-494846073292941121091010560000000",
+        "3317765887009185280000000",
+        "1",
+    ]),
+    (-328, &[
+        "88955608603044673650138130944000000000000000",
+        "54802167111836784369290132453376000000000",
+        "11610744584144462730131436503424000000",
+        "-5127512346913614444576000",
+        "1",
+    ]),
+    (-340, &[
+        "43039377624755967291385639037347037184000000",
+        "5906485031594874833231597894020684185600000",
+        "-54548817402421378465247510316573696000",
+        "-14383245771217510630675200",
+        "1",
+    ]),
+    (-352, &[
+        "233814863662235465757810993000000000000",
+        "149685745558232337507189972000000000",
+        "-99328134588221245548396654000000",
+        "-39625012256717991533364000",
+        "1",
+    ]),
+    (-355, &[
+        "167490001660588917859010199158784000000",
+        "-24013762453779394698078584832000000",
+        "6828932041616339922516443136000",
+        "50912008581334742581248000",
+        "1",
+    ]),
+    (-363, &[
+        "1577314437358442913340940353536000000000000",
+        "-496864268553728774541064273920000000000",
+        "45688143672322270430861721600000000",
+        "98823634118413525094400000",
+        "1",
+    ]),
+    (-372, &[
+        "41393149892607462736698558825033501904896000000000000",
+        "1755509254864401819594526832548625909760000000000",
+        "-2969541010382978868435960918595200000000",
+        "-206603714804587147622880000",
+        "1",
+    ]),
+    (-387, &[
+        "587533119951491680960512000000000000",
+        "747948304897905210163200000000000",
+        "-1543710173513675490459648000000",
+        "692535742940813486100480000",
+        "1",
+    ]),
+    (-388, &[
+        "-1121692648948590091501551223636881408000000000000",
+        "208224136957169320201407896480139264000000000",
+        "-20542159225989612130996373047535232000000",
+        "-750062398364686994581728000",
+        "1",
+    ]),
+    (-400, &[
+        "87448873738295790450948276123544550117376",
+        "-19075061455767889406477974994607212544",
+        "12869286863161864184636279443710336",
+        "-1938773508354872717845384224",
+        "1",
+    ]),
+    (-408, &[
+        "13375974716483932888129605820405217248677888000000000000",
+        "-334918514756463762318006309600841904719872000000000",
+        "218066148024051247931306674050097536000000",
+        "-3622859125108878497350176000",
+        "1",
+    ]),
+    (-435, &[
+        "-12512019875237835915942574589201734434816000000",
+        "42866222697779107335351550466659555737600000",
+        "87465379468169320817492479772196864000",
+        "28597298728131202056826060800",
+        "1",
+    ]),
+    (-448, &[
+        "-8964424282273362890505339044524081787109375",
+        "18314847446238545696830716579562500000000",
+        "-1251995474985759392628697477841250000",
+        "-75579535015741588088518020000",
+        "1",
+    ]),
+    (-475, &[
+        "-241309172434875116180341986667714653978624",
+        "207773856463139688132677943482420035584",
+        "-18897945215696864441083229261266944",
+        "544368813426921255662610284544",
+        "1",
+    ]),
+    (-483, &[
+        "-296241507936739247491345278560108544000000000000",
+        "160587932046974848398336021151875072000000000",
+        "9557426544972522152310585774047232000000",
+        "966618711103413979025620992000",
+        "1",
+    ]),
+    (-507, &[
+        "52801843586884277419825606742991962112000000000000",
+        "2658551097838253705341260639403769856000000000",
+        "91537611307702496719179485710123008000000",
+        "5262585338995033221326389248000",
+        "1",
+    ]),
+    (-520, &[
+        "171517475891022372428505519185548559222346497654784000000",
+        "-78006534528871949845908360976579586206001479680000000",
+        "46650003139146307922421888174845453223975936000",
+        "-12958889442406058296422344736000",
+        "1",
+    ]),
+    (-532, &[
+        "-19077542993352945680961028994697271308288000000000000",
+        "5131537740610192962070880163006969643272192000000000",
+        "-160054212938390343773833947283393690785408000000",
+        "-29478909019098139074177479136000",
+        "1",
+    ]),
+    (-555, &[
+        "-532755731205331063356397364951543957176713216000000",
+        "19282254568556435196991625190065063388512256000000",
+        "7191013406366483381037450688276469907456000",
+        "138859536630220704987259502592000",
+        "1",
+    ]),
+    (-568, &[
+        "17903747548118085544966894162888109264474112000000000000",
+        "-20244861194040338252021384794239225557256192000000000",
+        "5960215994584814927107650154330552605647232000000",
+        "-328731508303364809994652861984000",
+        "1",
+    ]),
+    (-592, &[
+        "62432994413265034555246757429466475569000000000000",
+        "-466359042858849293316032894138251092000000000",
+        "313250432161122948352163248725179238000000",
+        "-1572930194931239851927605196116000",
+        "1",
+    ]),
+    (-595, &[
+        "-91399742601830803813322386656934773129216000000",
+        "483054636550112292687021684688517332992000000",
+        "8752111455147508300981595950899265536000",
+        "1908606683491595666107623383040000",
+        "1",
+    ]),
+    (-603, &[
+        "469351599148575084436348037234688000000000000",
+        "3412681538174356730332626026496000000000",
+        "-7118624290306318120963251634176000000",
+        "3189372971004509360884026494976000",
+        "1",
+    ]),
+    (-627, &[
+        "-1261687189208313891495979730091871567872000000000000",
+        "526326624169690832922357632213666758656000000000",
+        "3563858169242172480409901737583233204224000000",
+        "14586137722924213400310156521472000",
+        "1",
+    ]),
+    (-667, &[
+        "-278701754438991300992352387072000000000000000",
+        "-147087485221823269890900432519168000000000",
+        "-3737847346141410401145461932032000000",
+        "172524940705544715709707399634944000",
+        "1",
+    ]),
+    (-708, &[
+        "4046686423378034814414234559373865948538701215210194862739456000000000000",
+        "3603887011528002652771717224491220641587422892784070051840000000000",
+        "-2854565250565963840094617979015298078098347812480000000",
+        "-2012303924332635494819557244440800000",
+        "1",
+    ]),
+    (-715, &[
+        "13189879204176058896562640516998642620432384000000",
+        "94657547256854352451418607502680693669888000000",
+        "60156378344564221943954774472086041657344000",
+        "3038922093329613647424771157499904000",
+        "1",
+    ]),
+    (-723, &[
+        "43799003445375960815587788104700084092928000000000000000",
+        "-17437817166277457429521660531780027831812096000000000",
+        "8222450770908698023546828197247145547399168000000",
+        "4855690107103225136120718536060928000",
+        "1",
+    ]),
+    (-760, &[
+        "57390991709103678336339431944416743303984993656228540622045184000000",
+        "-8762694788548498478760416933120597566268079681131589510758400000",
+        "262960509575258849119050573504013616920976671774792704000",
+        "-41045008988631123111685822548134227200",
+        "1",
+    ]),
+    (-763, &[
+        "1212202634617724845661254714392576000000000000000",
+        "3730143008151395358758986101112700928000000000",
+        "11764579526453656222964578511153528832000000",
+        "48688224497542950284157258615128064000",
+        "1",
+    ]),
+    (-772, &[
+        "-4039979678479578220330132982722340932044073244946432000000000000",
+        "-1654219429424921222911088262751088404746562249930752000000000",
+        "-730409189972766569984362477406681962614314316392064000000",
+        "-81104350841312411963776730201270496000",
+        "1",
+    ]),
+    (-795, &[
+        "1580866394929445594613317271657673734190830966521462784000000",
+        "96989374802114211792220362019627433906928110027145216000000",
+        "1962512368737475150054890329369747830206508302336000",
+        "294853904675299611949375562546552832000",
+        "1",
+    ]),
+    (-928, &[
+        "221147287295840631009155378215919896241234993293617000000000000",
+        "13390564998216290984073146179239751905642859284000000000",
+        "-8992980876865725569995845414882451608653638542000000",
+        "-365698321891389219219142531076614125108000",
+        "1",
+    ]),
+    (-955, &[
+        "438953058221654415262613188100773336407392447044238966784000000",
+        "520207875218635547684744626511303352924946915393536000000",
+        "396469707692607651662987973604670339150203846656000",
+        "1456880094856940116294718071366713311232000",
+        "1",
+    ]),
+    (-1003, &[
+        "15040125689821293744115482557611348328448000000000000",
+        "1305202673705533598197444367081354312024064000000000",
+        "-204493994631228266186213761658603748458496000000",
+        "16219528503217062422459730048347378577408000",
+        "1",
+    ]),
+    (-1012, &[
+        "-204344290478354698106731378125784194576718103432833630334976000000000000",
+        "1243508019466325039942928040075544459231301705571821435488256000000000",
+        "-127409933077387882483393397275452601210672521773684542789248000000",
+        "-25320300665394312513202440887044222231008000",
+        "1",
+    ]),
+    (-1027, &[
+        "271046093357449955035386983426329999080765259776000000000000",
+        "164592522336657395778809121398601659935044403200000000000",
+        "-41547404176734721779832688271619104304005120000000",
+        "52960452958968182770743647384658280611840000",
+        "1",
+    ]),
+    (-1227, &[
+        "2513550635275580846572126510888944466103176257943640211456000000000000000",
+        "89252949391959745426288430543461455160950172376987709997056000000000",
+        "5282646588767618158994396140387285593346806931114470408192000000",
+        "619638890847298092963653036606353098743021568000",
+        "1",
+    ]),
+    (-1243, &[
+        "30540293156908205255120060127032312602199851008000000000000",
+        "-54665859623503521460552388837431864605259333632000000000",
+        "26521598516319200744664388741019144869638045696000000",
+        "1266871605300222128375795939246750405038301184000",
+        "1",
+    ]),
+    (-1387, &[
+        "2052723014407052457647477199445183281503328534528000000000000",
+        "36686449491372953371348633076156538920834099576832000000000",
+        "8421392423043512311845823062070841518683467022336000000",
+        "649705640341533249055461232040056199884943609856000",
+        "1",
+    ]),
+    (-1411, &[
+        "259898672030231371072634921991495299708204070671565541211890306346215538688",
+        "563159340355333157360236566159729674583227014555157757651685811744145408",
+        "19568314960219288785284224576189610670941760495017044362657792",
+        "1780126746705689756102562231651060896708610079948800",
+        "1",
+    ]),
+    (-1435, &[
+        "-4009811510734177961140258455491639109235283276327883374802990268416000000",
+        "2539970793779946148723473087883821948343643667859030533390886502400000",
+        "105161502065491843193116493512870644772977750388041962225664000",
+        "4835907878329132222450395857259654466718969836339200",
+        "1",
+    ]),
+    (-1467, &[
+        "4750778730158046729996627074508314393022035482908819456000000000000000",
+        "19361523208256264971288945998620952474692182081536000000000",
+        "-40389436387354150167355479516145857227679829327872000000",
+        "18095625621654510801615355531263454706630064771072000",
+        "1",
+    ]),
+    (-1507, &[
+        "946755971011460406830147750660957594274801022094278656000000000000",
+        "-131730022847167071512725748945146741381253488296591360000000000",
+        "3693591679022156272138192761442250249057420055674880000000",
+        "92304656744815388412175046838197961483773831208960000",
+        "1",
+    ]),
+    (-1555, &[
+        "179277385817055839939036171839607344168985308854293076933328502784000000",
+        "-153802169705179237851782806689208034512876098346328765182771200000000",
+        "36521008026523717023567141651588968008073653196177682701746176000",
+        "634043412248649501919536531936002831564519413161984000",
+        "1",
+    ]),
+    (-47, &[
+        "16042929600623870849609375",
+        "-14982472850828613281250",
+        "5115161850595703125",
+        "-9987963828125",
+        "2257834125",
+        "1",
+    ]),
+    (-79, &[
+        "5458041030919737322344464663391",
+        "-5859423003994491322155950334",
+        "1793441424178093483069839",
+        "-6366718450945836",
+        "1339190283240",
+        "1",
+    ]),
+    (-103, &[
+        "28826612937014029067466156005859375",
+        "13355527720114165506172119140625",
+        "4941005649165514137656250000",
+        "85475283659296875",
+        "70292286280125",
+        "1",
+    ]),
+    (-127, &[
+        "319730671478833667491273673675537109375",
+        "-64331030949386896516600669921875000",
+        "5642626198092219066070054687500",
+        "-30614197896114609375",
+        "2375421230598750",
+        "1",
+    ]),
+    (-131, &[
+        "144530638394690224075155326369792",
+        "-60354680538951673475558801408",
+        "107205484283838454093053952",
+        "-671177121829224448000",
+        "4130485792112640",
+        "1",
+    ]),
+    (-179, &[
+        "69366107283027836458026686806432415744",
+        "-23408814596997033103434472837087232",
+        "2672564790656716736213209317376",
+        "-2200273236852299356176384",
+        "1795194552944492544",
+        "1",
+    ]),
+    (-188, &[
+        "-124343484762728525316005706787109375",
+        "51644103814690479844366699218750",
+        "-15093437571402131169817626953125",
+        "-8766069746614632866828125",
+        "-5097838276115828125",
+        "1",
+    ]),
+    (-227, &[
+        "5085472193216544027705344000000000000000",
+        "-2111118203460821622718464000000000000",
+        "18227340807938993794580480000000000",
+        "-2562327002832961536000000000",
+        "360082897644683264000",
+        "1",
+    ]),
+    (-316, &[
+        "-4020052207065827322924749407724450555391",
+        "-2607636724575654685306407800818058910",
+        "-9055029354307022862746345001271887",
+        "80751461998466194163273841876",
+        "-1793430629453261849626872",
+        "1",
+    ]),
+    (-347, &[
+        "184912732321277851630780880519168000000000000000",
+        "-76862513895106262259943954448384000000000000",
+        "2286617351979618165608274471157760000000000",
+        "-7715358558261498003922616320000000",
+        "26032472194627246481408000",
+        "1",
+    ]),
+    (-412, &[
+        "-4191705834203632196398028235232269287109375",
+        "1091737124643141462807028029359619140625",
+        "9115476296360278267158084562500000",
+        "-45283522158500555084406507703125",
+        "-4941005510420834249209618125",
+        "1",
+    ]),
+    (-443, &[
+        "1580383899632304069192804677639613710336000000000000000",
+        "-645677619572710007907896290848702201856000000000000",
+        "726664457760516471225292785548752060416000000000",
+        "-194566138410048201097018632830976000000",
+        "52095503201744864610381824000",
+        "1",
+    ]),
+    (-508, &[
+        "-23012491049940208859981226392177581787109375",
+        "53837780625702373569505874473236328125000",
+        "-225348493809521765537422792233398437500",
+        "-46144011598251049101578232084609375",
+        "-5642626022844047596709323728750",
+        "1",
+    ]),
+    (-523, &[
+        "3397618365767017867913805692928000000000000000",
+        "-8335801454396454796105214853120000000000000",
+        "13395061255385032931309223149568000000000",
+        "-236957616665436077155248242688000000",
+        "15928926361335375229020229632000",
+        "1",
+    ]),
+    (-571, &[
+        "15283054453672803818066421650036653646232315192410112",
+        "-16319730975176203906274913715913862844512542392320",
+        "4398250752422094811238689419574422303726895104",
+        "818520809154613065770038265334290448384",
+        "400497845154831586723701480652800",
+        "1",
+    ]),
+    (-619, &[
+        "1646062182335949197810917415545902866747444946845936123904",
+        "28493830345553696446401792570748375365356507652685824",
+        "326238883724948585436745550058572488040138145792",
+        "-87016716912343398450728998742124757254144",
+        "8816350462749494490997859322396672",
+        "1",
+    ]),
+    (-683, &[
+        "26166115688569819428666837825663510027688881422336000000000000000000",
+        "-5025162304773332210314910428254527856822229389017088000000000000",
+        "268100148999161642690747540961044577891601065443328000000000",
+        "-348849132150121827613917484442174019862528000000",
+        "453918858809750227974703697494016000",
+        "1",
+    ]),
+    (-691, &[
+        "2881012171895295750002031701073564303892269058276549227184128",
+        "-9744515674227833316204230178037052905354521396539031552",
+        "162256308439015306053871060497929160439748974608384",
+        "6703571995070311798431761340831465046278144",
+        "733155214316421345930476736919437312",
+        "1",
+    ]),
+    (-739, &[
+        "70879256868963610140332287398622548391739243260758328344576",
+        "-39465098292737222691630856494517926607508369968554049536",
+        "8435175478606501944760514102198191107943823755444224",
+        "-521202850366310037383168135322581564217360384",
+        "12301647126979210892135760009684713472",
+        "1",
+    ]),
+    (-787, &[
+        "121799441042213268250468077932063490048000000000000000000",
+        "17969534868961880720450029518624242270208000000000000",
+        "16295872719960594906811990015695057321984000000000",
+        "69003057677093510781188231291411103744000000",
+        "188607826190137802296622247543103488000",
+        "1",
+    ]),
+    (-947, &[
+        "20199776314224058950365890537565315358777099022469188831674368000000000000000000",
+        "138392022610291656780042207674445576504764510661377409324941312000000000000",
+        "9493349427387940653432106335736508248255562379877296382148608000000000",
+        "-95925831671373391613819628116008945992178543362048000000",
+        "969285419584981893143448179709100896256000",
+        "1",
+    ]),
+    (-1051, &[
+        "8743043565409016736756337526364572620943665551429688944132020955512832",
+        "609478936308852703138855519171409018549012233989963733448483930112",
+        "84072685629795186930092590274005854385288302697479331815358464",
+        "74010210743744060468842095314484372058081554805555200",
+        "170566836806239391545422096319774885221433344",
+        "1",
+    ]),
+    (-1123, &[
+        "1809906887755628098044099196443063823751970816000000000000000000",
+        "-11882827525751670729580856303717922468701235314688000000000000",
+        "60970880543121897498770914917110161705048360353792000000000",
+        "22349204940161721553488598505833419202533261312000000",
+        "5270819845653305357116482008859297909325824000",
+        "1",
+    ]),
+    (-1723, &[
+        "22117636276671147862840290439621705704748794677800451702784000000000000000",
+        "-25585666741501267588751437947480472614496645764581393694720000000000000",
+        "8147352112441079339114932116215629522948839835223967399936000000000",
+        "100007681683305113853711203615180993147700655452479029248000000",
+        "430473126428685627917807961121230497816202334067122176000",
+        "1",
+    ]),
+    (-1747, &[
+        "10972276008883064285080011870718249401446538792690253824000000000000000",
+        "490532026749899358428380180583698110640057609931980800000000000000",
+        "-5035877279568158128379680839999923826224948571172503552000000000",
+        "-1073980648086639795645379984231813344252858982220169216000000",
+        "1064178758997417055856124713452790702387631331694727168000",
+        "1",
+    ]),
+    (-1867, &[
+        "328043617100733300406098627198236644161849525109185520718517210187825152000000000000000",
+        "-417666456716082105125094342082637063107409343852345940493085161029632000000000000",
+        "6263821649035332499821670679192496191611885050408415035156766654464000000000",
+        "-10557876447102477661186151238644596127207141266739286333456384000000",
+        "89755014833202823144487504645057894143187931042124775424000",
+        "1",
+    ]),
+    (-2203, &[
+        "217049315120963111446606786614831619271467826257075291824999096037895307264000000000000000",
+        "-136125812590732504314763782112143869965497676192764476144106058214793019392000000000000",
+        "21640569628318338155418587236281230588430084772239571168625744182002057216000000000",
+        "-6844014255307220707700878302398568223491614384979193414938903183360000000",
+        "10928022023566789669783068114819419427253014142627272930054144000",
+        "1",
+    ]),
+    (-2347, &[
+        "901644569922562159908075052673194391591813593885976890680803328000000000000000000",
+        "-275005605893225872283528922063608430694556156276885172134243991552000000000000",
+        "-3974980164212098708353473429081552320904452727132783329325088768000000000",
+        "3850392729356723089776031377897241173629209744573099844793532416000000",
+        "1254200008892825161202591449810348856556695501877957727675109376000",
+        "1",
+    ]),
+    (-2683, &[
+        "401582160082250340418163965666567927009157113311106944233010240700809216000000000000000000",
+        "-302940399280383744660448145173115256445404252345773292018673267122565545984000000000000",
+        "332217934831843316166822577187571931306604468831007814954178159155441827840000000000",
+        "-239604677226145548257568705238807644964803277307165530139680701834854400000000",
+        "46939054890138039541090570578284916766453800683280728875816043274240000",
+        "1",
+    ]),
+    (-87, &[
+        "549806430204864490157810211181640625",
+        "432181202257616392838287353515625",
+        "497577733884372638735595703125",
+        "28321090578679361484375000",
+        "85585228375218750",
+        "5321761711875",
+        "1",
+    ]),
+    (-104, &[
+        "65437179730333545242323676123103232",
+        "-25735039642229334200564710375424",
+        "1378339984770204584193868955648",
+        "31013571054009020830449664",
+        "739545196164376195072",
+        "-82028232174464",
+        "1",
+    ]),
+    (-116, &[
+        "-100730316193548175256338136121783353344",
+        "143376986667050616958401264069115904",
+        "-66527716583835083670963399688192",
+        "-835102260960042427461140480",
+        "-11056847669496432594944",
+        "-495202728828032",
+        "1",
+    ]),
+    (-135, &[
+        "3284527439242119311242957750346113869140625",
+        "4321223868213674595045534006061857421875",
+        "628735820731639650833126829398718750",
+        "50727257383070661992492657625000",
+        "77686119211324699125",
+        "7122306993287625",
+        "1",
+    ]),
+    (-140, &[
+        "242830180406000275493501698048000000000",
+        "-96864973869318094511286681600000000",
+        "2848295663082788926282752000000",
+        "-790870172407252503705600000",
+        "-3270237719203124384000",
+        "-13916138006442400",
+        "1",
+    ]),
+    (-152, &[
+        "472390748138731280269312000000000000000000",
+        "-1380504171426125758791680000000000000000",
+        "2783058624787093614292992000000000000",
+        "6854544294799483688960000000000",
+        "17024071380555203520000000",
+        "-66246265919280000",
+        "1",
+    ]),
+    (-175, &[
+        "27017288450887144631231387755756779460197062625",
+        "1368302291061523680379707879639549158890532250",
+        "-59496933313401566319649813402788210673425",
+        "1253156381651642217978286627708618800",
+        "-54813228576976021387185",
+        "1119444674983992405",
+        "1",
+    ]),
+    (-176, &[
+        "984315149136933710414929915123613725364224",
+        "-233832181396031563359165936367916838912",
+        "56139914410303801525997336800408320",
+        "-1417657940638726253547455241728",
+        "-1311227225704547834164432",
+        "-1260369120052221040",
+        "1",
+    ]),
+    (-200, &[
+        "1139359927820736630329093876556526883461660672000",
+        "181651879545544879923314552485535205556224000",
+        "640004261883602853633325553571308188467200",
+        "236628493411489493484987107953868800",
+        "87605036675549431339528253440",
+        "-19733105507276110720",
+        "1",
+    ]),
+    (-204, &[
+        "28508041377034538166862450172153093456658432",
+        "6020337293681148983229932704488367325184",
+        "534484930703209896960446929872814080",
+        "775121756231241041610849730560",
+        "95864841637996112067555072",
+        "-30703802307926880672",
+        "1",
+    ]),
+    (-207, &[
+        "-183426864580818496179793649372867188930511474609375",
+        "12041028291910181818274355885092809398864746093750",
+        "-210672109851582446065248197114115955810546875",
+        "1819343755841562591564610147379736328125",
+        "-5002547112103664005187500",
+        "42653766018394018375",
+        "1",
+    ]),
+    (-212, &[
+        "-67450134022842979455115194007552000000000000000000",
+        "39924086528997881772669622484992000000000000000",
+        "-11008353578715780277672803110912000000000000",
+        "-2630171369254890916959016960000000000",
+        "-628986407384453487358016000000",
+        "-73387074029381328000",
+        "1",
+    ]),
+    (-216, &[
+        "9316863967448371969962043382716305179148288",
+        "34441927383131420224661352250608420126720",
+        "129141069874109492050243812631108386816",
+        "-9056150910670523557375044574248960",
+        "1197383573848845385478924955648",
+        "-112760061542456628096",
+        "1",
+    ]),
+    (-244, &[
+        "-9815190670232173018201554731440614047465078784",
+        "-24417475317780070950649666808040757791817728",
+        "-31292753080096691789898512325924416913408",
+        "-2691275293785918359227938328726732800",
+        "-92973717558373200586964869091328",
+        "-2052295773725248986240",
+        "1",
+    ]),
+    (-247, &[
+        "-407336295332190846580777495118233696120388820648193359375",
+        "400348022833121004028281794619328068026346954345703125",
+        "2475076441475565987510057965501956793452880859375",
+        "7686260773063033411724550958439950781250000",
+        "888629892547516768433109375",
+        "2772410642909877080250",
+        "1",
+    ]),
+    (-300, &[
+        "66661978554978958501295319312489107870472732672000",
+        "62082816308629282586712746552975312469884928000",
+        "21122955530832902270001123584504233628467200",
+        "-233405320133674124312518469774131200",
+        "32278855882815402576742692253440",
+        "-428244362959801779810720",
+        "1",
+    ]),
+    (-304, &[
+        "15932156097435253410695381729009112036477505536",
+        "-7791225706498882399085516838831185545949184",
+        "1528346968785104896068323613180201480960",
+        "45378991837771215221573080886813184",
+        "491686666270349693036542004016",
+        "-614772722255836378993776",
+        "1",
+    ]),
+    (-324, &[
+        "-5061569724026170821913022827171999159041259361796096",
+        "23450077655748415730757884569939472490942286528512",
+        "-44745106433723692853594036259593171592240168960",
+        "-895899811825836113012019702876374414917632",
+        "-6889085634124971952835571514757910528",
+        "-3620548496603402008963200",
+        "1",
+    ]),
+    (-339, &[
+        "419198194184232019280311537075670994855640493457408",
+        "114053138969457254141239955759498317338331054080",
+        "33494559320437814886965525300815718579699712",
+        "-527926973475401681480399895797881110528",
+        "3119834163056249586908843992940544",
+        "13207870721923966705729536",
+        "1",
+    ]),
+    (-348, &[
+        "692471208858544440424465339478399814605712890625",
+        "-557628723010969157792086142065436920166015625",
+        "175290702692535339653340200368891845703125",
+        "-29003873161616986468921515894234375000",
+        "8632839017824414061478365881968750",
+        "-28321147554737698717243875",
+        "1",
+    ]),
+    (-364, &[
+        "171457302312675538238450398825818559998229834899652608",
+        "-26779040013783862224508884712755413392899723755520",
+        "-280762269320568601992086689163982842916569088",
+        "-1114180967095768084290666504793175015424",
+        "-26945321066119881266121528480000",
+        "-107310393727516367835689376",
+        "1",
+    ]),
+    (-368, &[
+        "13801356390308204318085687229009161734928134918212890625",
+        "64648812618671323095979973667040653072044433593750000",
+        "146380756273380282900056220504251687443860595703125",
+        "-41615501434303103741344598557507373625000000",
+        "-78492653237795758158907987231593750",
+        "-149030963654426202395206000",
+        "1",
+    ]),
+    (-396, &[
+        "7704857441223851094181212264701978984938736673278132224",
+        "-3882916848674920220024612236964484759319495663681536",
+        "9042581842659781853871610042261233665687093248",
+        "-53329107003371259961886231710373417500672",
+        "242487456790940831195516923473664",
+        "-1414968042064378970672787872",
+        "1",
+    ]),
+    (-411, &[
+        "73029635693775668009059727434983158067960210151050313728",
+        "-4679673657864301179943258144578870967000652618661888",
+        "870431545791433602355093719805848213678257602560",
+        "1544633353160505212381702428744859800043520",
+        "7591033806233449501451135280463478784",
+        "4572839098768838399956942848",
+        "1",
+    ]),
+    (-424, &[
+        "256124659472476156429866214718645776584030123766932075184128",
+        "-473081446853521752764184578414407578553597912291215409152",
+        "236813677534123887255838256365810161940182080793083904",
+        "926676088876656917610604147887399839119029829632",
+        "1384659323070129593431064385863072408432640",
+        "-12423061195029429537745759104",
+        "1",
+    ]),
+    (-432, &[
+        "42889619864187195342544128412237640625000000000000",
+        "3869372376492639837782614434923625000000000000",
+        "34904627315764077727184412247908187500000000",
+        "1007059405271040783775694468925000000000",
+        "280179539493990596285512318134750000",
+        "-22804995243537595825782822000",
+        "1",
+    ]),
+    (-436, &[
+        "-3309564689920675611841021602429307330976712868047012658937856",
+        "4190391048071364469026866970440363614326632840014231240704",
+        "-1352721253689086960917768809906285566915367850230153216",
+        "1693419722764462128370611200560660741876060520448",
+        "-5414046507161941300684943471721179845005312",
+        "-30832919939688372877918428288",
+        "1",
+    ]),
+    (-451, &[
+        "248738232385414940352605447987918942762879391105024",
+        "6538354632239239965706431356575791482338510110720",
+        "584470709556329910881460450936625902429143040",
+        "37281139264035594329231801543794366611456",
+        "37732368326837192349624395555143680",
+        "94391735188170044104985346048",
+        "1",
+    ]),
+    (-459, &[
+        "-313486865139741282237104926958928242066616974573568",
+        "625812947073456566584812280878079219774059970560",
+        "-347902720129642555336433488166664447480299520",
+        "84716558562768338346996644375759262056448",
+        "-194122915779480469587085735929839616",
+        "170132875256900610745209225216",
+        "1",
+    ]),
+    (-460, &[
+        "12543639480429066293430609355779410430756351541248000000000",
+        "276071822532474892071316268722729067476434124800000000",
+        "55421606473085222373684460941329804874243072000000",
+        "-78327919900899962643340281002349422131200000",
+        "129803048105744559992341062593376000",
+        "-183068125539610033421078032800",
+        "1",
+    ]),
+    (-472, &[
+        "609118140629014547427739243406522843136000000000000000000",
+        "7782762847555792408664371720856640749568000000000000000",
+        "89663269021650272593765224657345386704896000000000000",
+        "-6621978932864958986465185964976874629120000000000",
+        "290243510038159955925726906822209766336000000",
+        "-438370860938320369278668592000",
+        "1",
+    ]),
+    (-484, &[
+        "-790804636706798790802256045970879381853704727181511395005956096",
+        "763766482177380291593937467835962528924999624812141731643392",
+        "-1071309761927862459988530157923643325520271635829972008960",
+        "-1722172017117768441995881689468749598391253068677120",
+        "-1057839251114856607055720360537330350583746560",
+        "-1038197035676506069321210304640",
+        "1",
+    ]),
+    (-492, &[
+        "10931222269636259868789874889218075826344512000000000000000000",
+        "-33515822578148761626323796875644246586347584000000000000000",
+        "30393066643081701907199820448173303282643440000000000000",
+        "-2541265400786050984712679817021050336000000000",
+        "22444442051052113469935717401578204000000",
+        "-1833713665546358542159577412000",
+        "1",
+    ]),
+    (-496, &[
+        "61992798182727056220505257386995728000704825102649533554689",
+        "5182664622152485722685558956575902930955839208869950432",
+        "134375279197206001252258427214455143691428583838378",
+        "-70831581292550651074090944215955019084774944",
+        "97788423170643342343762148048633899941",
+        "-2432787421339920412482146578800",
+        "1",
+    ]),
+    (-515, &[
+        "44195318902652537887832280872617801166384771408066510848000000000",
+        "-3107115217734912014460860938978340298227921413210112000000000",
+        "6259740439766871889137441931457556788367376087252992000000",
+        "4021108268646819914222211212116150442157670400000000",
+        "-192074704335054414490094597558883057664000",
+        "9175438450996787302014492672000",
+        "1",
+    ]),
+    (-531, &[
+        "253019721297801601360183161111100670441677888450509930496",
+        "89695055074984733543572254320373841113802328038703104",
+        "-5668347118698040482683727597487173897920299663360",
+        "101913303540872546991651789090862425056477184",
+        "31476922594694825843813187240301953024",
+        "27537270228600020374834178392064",
+        "1",
+    ]),
+    (-540, &[
+        "94309638503639876530118685319843252440163328712890625",
+        "5566107605838844555689235368518602993799267578125",
+        "8447962021182385992754037292591932158628718750",
+        "-1406534299971645322507825760505592082625000",
+        "111924477234172546946300759710144789125",
+        "-50727256906489049145803328275625",
+        "1",
+    ]),
+    (-588, &[
+        "2320214404236209026697667252031829533741926076992000000000000000000",
+        "-6126070918172001115197989066875857222316541112384000000000000000",
+        "4515181954964345872129019514813523000853053374960000000000000",
+        "-42689249963097095301009077305839336539616000000000",
+        "129566015701225407418600263236982900444000000",
+        "-1214418339247561600451288629572000",
+        "1",
+    ]),
+    (-628, &[
+        "-193480668290139827978551941260794168997079416832000000000000000000",
+        "2244973573297457770564792736595644257458378178560000000000000000",
+        "-11306325980421358690756668239288149306045473558528000000000000",
+        "-62749081242237832118702516655452609031428167680000000000",
+        "-1935352780955944330159655079539039563020333120000000",
+        "-15530070499668424480113373087440000",
+        "1",
+    ]),
+    (-648, &[
+        "420259747133910150639354663643453078777790464000000000000000000000",
+        "945704570192557917123296384393395078314143318016000000000000000",
+        "708496467215673879034146895464634305344685797376000000000000",
+        "91062241689319860183508830989589146658538772480000000000",
+        "12503387249746481540592689090608670703243756480000000",
+        "-53870596955882124816323280316464000",
+        "1",
+    ]),
+    (-675, &[
+        "-518934241375857119324489861138068922472670611351011328000",
+        "365545010929303872566806343360090400895165551083520000",
+        "-40403338809853991973278867579964893049937251532800",
+        "3283548433378494044784212121590732589681868800",
+        "36741609284830353098825275576161864253440",
+        "280244748103684391377172529765089280",
+        "1",
+    ]),
+    (-676, &[
+        "-437940714559143999422451459680237045189874838812636812209273628143801860096",
+        "183121307244468811013362819441915945367491906284343782971561865394520064",
+        "-25139996004850385022058823419251332525548857652725838427880085782528",
+        "1250093798808181921331239024003439064057314451090248756625408",
+        "-162434321923500244963691319577164899941782327177547776",
+        "-297704363274819300973648925452724352",
+        "1",
+    ]),
+    (-688, &[
+        "35666765383644406075046298046290446413640625000000000000",
+        "418692965492345733785114777449885492571625000000000000",
+        "2247353146940073239738967954039378310380187500000000",
+        "-1563641315241543882064062754494470169795000000000",
+        "542090882349799145243237595878239747254750000",
+        "-612711818312922195678985083375654000",
+        "1",
+    ]),
+    (-700, &[
+        "1279550552930955466965967752790818024379275213338521562625",
+        "-857637007838292266512501851631510160613001848780896250",
+        "152994800337304876257175053273288365860639529326575",
+        "6145165148367786469915434874758615187908118800",
+        "131092763407177117096672838357506053862815",
+        "-1253156380350127685465362671290113845",
+        "1",
+    ]),
+    (-707, &[
+        "45650758520764343599531218962600327069641099781865472000000000000000000000",
+        "6903373536375246004273099602846064408758831459747233792000000000000000",
+        "483991714896097062009964822901019056444300864589398016000000000000",
+        "2906768622940702048127349597557735037973448835989504000000000",
+        "-2348163642809062173079548581285822944247808000000",
+        "1896908196134808026819028803674112000",
+        "1",
+    ]),
+    (-747, &[
+        "122610264884195004276135169939960886198272000000000000000000",
+        "-64835561517901382404643579646446405681152000000000000000",
+        "6153305059000738275431089671195439661056000000000000",
+        "867239909489765164489182213498417971200000000000",
+        "-1739910303132650092314136789278261248000000",
+        "19506549129659843880518872730185728000",
+        "1",
+    ]),
+    (-748, &[
+        "14102760806179287374525913233889892863270846335963712000000000000000000",
+        "-4161062207951390320730581122152303002996237381468224000000000000000",
+        "-35789562604701242403041126998666027131602379470352000000000000",
+        "-93907027012642433024639652171634097345735823840000000000",
+        "-7843166260951182924654299188909364004000000",
+        "-20660082823607096260557693839278404000",
+        "1",
+    ]),
+    (-771, &[
+        "1158874532512529823117839025885691033242715995831907669464960496690003968",
+        "-1429554314369800014560099942657739809600837745393128794016090721091584",
+        "463885955604298483342404429131145213835946162203272517583917547520",
+        "7594080830144514531813778205972493677835173653113137528832",
+        "325572209586735477136602675439314988370753017085952",
+        "76644978500101583982634373055498485760",
+        "1",
+    ]),
+    (-808, &[
+        "47935532966149606407423899542710890275091793038645919744000000000000000000",
+        "181128288867528862366782440535423928657211618498508029952000000000000000",
+        "1803501184045414415458226074594817032543094739149616377856000000000000",
+        "-6485648984910606765832741139482781395227930706015758336000000000",
+        "14938062448136132459695331800504856899108496990798784000000",
+        "-606544748743842831563632744122859056000",
+        "1",
+    ]),
+    (-828, &[
+        "994085932928328513269075382688739701324515086338043212890625",
+        "-1055648796239235006353912975802938543893809630310058593750",
+        "303627853695026697747014082081770550922214087158203125",
+        "-6255952940792043686802768545302127864857236328125",
+        "-155241642964074826687355141409694317641437500",
+        "-1819343755551914380120575986714936366375",
+        "1",
+    ]),
+    (-835, &[
+        "4891900660599003311385391904709529453654625325416448000000000",
+        "-7042823494828064970531335733824610561942964980940800000000",
+        "6664997136310346124828673949691089890037414756352000000",
+        "-2065662052326646805861259358837766884548201676800000",
+        "204438038298621794574204160089592858008354816000",
+        "2663908095665787906008140453642906828800",
+        "1",
+    ]),
+    (-843, &[
+        "158692695909564629221707977026748300372656225517568000000000000000000000",
+        "73075310770259371090473394277205354753774863920398336000000000000000",
+        "13875309039130379123931424254934593471702463886131200000000000000",
+        "37712121952130322350424411490310586953108092354560000000000",
+        "65853429066427465015436329665082314084247928832000000",
+        "4110870386791186329312795254569168896000",
+        "1",
+    ]),
+    (-856, &[
+        "595320092985311485518113602681984286078598796937520420636189368159335021853433397248",
+        "100840602675506952168901909673258837400582800529922501097017442693461370191478784",
+        "6991369178933310977728137772066916192262112745060166206505651706920934834176",
+        "-44861747133100666670326391171999140977507020813422564697216507707392",
+        "753904043512209705196167696045982334339284730437509446135808",
+        "-8283439699523057056710299565708340695936",
+        "1",
+    ]),
+    (-867, &[
+        "54198575106762219309306750175869557905848927491457024000000000000000000",
+        "106110003377820534797452421571371781575278631527120896000000000000000",
+        "110435906540395590439728400091584740929517223963262976000000000000",
+        "-363299799803406415238234259440206899137613209272320000000000",
+        "367424180219313125835753436424699754428334342144000000",
+        "14923676060461817600380995991658397696000",
+        "1",
+    ]),
+    (-891, &[
+        "5168163648975512659218280004228058594242246960004693595942643222708224",
+        "1035035326134443716536282940826886761586345876366755233455717482496",
+        "-31854233116932749008032176737600378973050147231593732960681984",
+        "1038741776815098026658188487974579443177380368468924170240",
+        "4594037505619224384492487458826312531064723079168",
+        "53225524125202190209686089298526984077312",
+        "1",
+    ]),
+    (-931, &[
+        "-7386309756969448257470450133409239259554086107480959871912419262464",
+        "575189149356541771195221007548176708986040419379171425940605501440",
+        "168561941548969525986478743144511786925541326306064233857548288",
+        "19158475518778580965654795745833295381755715032783930785792",
+        "55890403399933273258794756214733278270255068610560",
+        "426825670734143049390226201308485409177600",
+        "1",
+    ]),
+    (-940, &[
+        "179388984738118075927335478384580041754700365040094034407582402510848000000000",
+        "150374151707312696807411533594380715749234259093054453174234214400000000000",
+        "129621471765539841075613092102924094889300530308678275627965504512000000",
+        "-557802358790342917462106846351452762488715684290139386624000000",
+        "157465813216968792011512406135114199587296070496000",
+        "-677621063891416076248230276783145353540000",
+        "1",
+    ]),
+    (-963, &[
+        "22576353114860596323808371902774966361260032000000000000000000",
+        "-11551747659112351822438130857235662422147072000000000000000",
+        "3886621812484169095239511756859674325942272000000000000",
+        "253765758001451434795075083364515023159296000000000",
+        "112488709831669838242898071905169624268800000000",
+        "2186030586436385809493739168780136288256000",
+        "1",
+    ]),
+    (-988, &[
+        "156923629064905439774176378010411051410367908309970855712890625",
+        "-851246405305114045923979169049333147116118615064727783203125",
+        "1439425711515104898407394446456770226677541331765380859375",
+        "-415425015530688765021462979459998135459997197656250000",
+        "2532835862794270727054106141876129096796815609375",
+        "-7686260772919956192092959105066444924388250",
+        "1",
+    ]),
+    (-1048, &[
+        "29454477481561065337739645086875288893634262511106481929070989017088000000000000000000",
+        "-25621049914343420689392039025367673119179757513095203019197049405440000000000000000",
+        "7452831769125999597702118084128588919955059910743673793703133638656000000000000",
+        "1617853253606236970710953212092360268707670074178388784079001600000000000",
+        "1790943047893740981132390014576016307007148883438139426931648000000",
+        "-147475943613655721880897041029867660392240000",
+        "1",
+    ]),
+    (-1059, &[
+        "54749382100303945965938377687131815951613999630363307989951555859309282920971108352",
+        "-68467787846615964253889968420566642459854805801826964850746468270218385188454400",
+        "91171383367443129855808350957355408805007188493743297041835744129102880178176",
+        "-194474091343506783682373629396296341554933178146702896739203931963392",
+        "158448918014406746129523347274938013298923809932524908445696",
+        "251140984312286759034108048655108324537663488",
+        "1",
+    ]),
+    (-1068, &[
+        "150987365419070887024382860867468133647475428945079644684937925201384000000000000000000000",
+        "-8263173087284923280313878351624537272370635985579915045534610518958144000000000000000",
+        "5559155688949726256614920324024047749692139559027298305540259943676400000000000000",
+        "-7626544315048813292199899291792967998530908307889776589280000000000",
+        "282433051177825517590691236172309271882488091122327772000000",
+        "-387424104936129233696550917778260484096324000",
+        "1",
+    ]),
+    (-1072, &[
+        "10771403633173887558715389348756131034781957045837640625000000000000",
+        "-38237960194013118522667341406580126256375888958452375000000000000",
+        "175758801049062616453336377002027949185418548361108187500000000",
+        "-205618168320113722528866065219311275381480814571715000000000",
+        "69104902721692050993732757792664181052336943660214750000",
+        "-469469171868912646848380230233174981468198000",
+        "1",
+    ]),
+    (-1075, &[
+        "-193287692463614614883338608255047893277791478477760472547328000",
+        "212204814360368170818793477323114082369152890857448275968000",
+        "214995298235242029368331836577539579500355536528028467200",
+        "68871046376981529174352392764446823706264504945868800",
+        "-995861976212308963874669710696037059914167746560",
+        "542088659144358007339967407586625308670689280",
+        "1",
+    ]),
+    (-1083, &[
+        "59183470775890623102362273565816658249475908317552333929054208000000000000000000",
+        "-29032393492809658592660308950281662196513701107834190854881280000000000000000",
+        "4966800676267052203103265355580743304785480055171066621853696000000000000",
+        "-855891373824131277999924218491939447670368650852072161280000000000",
+        "736126059004298167617725833874006982356590699109941248000000",
+        "794720003370771809323025500213738023874560000",
+        "1",
+    ]),
+    (-1099, &[
+        "1136813185931196805090835221189220662326985354242554724384883589416133767200768",
+        "-159957807627155985973845772410270888386713456569958064091773706333780443136",
+        "6031103667311076246161128355928344422120000526076426899664680163213312",
+        "2094712066990559001059860582304843256137699004807572833425162240",
+        "-3055628884137026639358575311444074263110618514158780416",
+        "1700869372837689862623335628779192341802188800",
+        "1",
+    ]),
+    (-1107, &[
+        "-4484734717995054917518881620708787284436704034816000000000000000000",
+        "4457558696468932915807721087710514950028390301696000000000000000",
+        "576603519172298968612826050475615700161195409408000000000000",
+        "25341222610540232773574921596117973778756009984000000000",
+        "-228333054151667629685434175906212150310338560000000",
+        "2483117566520491605893690385973749426167808000",
+        "1",
+    ]),
+    (-1108, &[
+        "-247139378620682941452553875146675251081843248745748494049974419456000000000000000000",
+        "49997233345143174809548676197872104879737690613211460200689762304000000000000000",
+        "-24353136016851142417213264085768694191955374076583496802241941504000000000000",
+        "-12534133693582485709775846498820786514500479100988745457653760000000000",
+        "-132814165888453325873288657098806887185672975644361253299515456000000",
+        "-2603132450413884702446245024069350283540176000",
+        "1",
+    ]),
+    (-1147, &[
+        "-4910240074893223291872087070464491467225807856861184000000000000000000",
+        "3425558159620577161079728245075966370657307832877056000000000000000",
+        "-554591662444162124799867520121911920095650803351552000000000000",
+        "257441396385104084140166784710633455033579349213184000000000",
+        "-116130636998144683505156435708506246624239943680000000",
+        "16138529448137876452423420675052001395564544000",
+        "1",
+    ]),
+    (-1192, &[
+        "300736284634655215079871589457449013099582150098887089147485618176000000000000000000",
+        "-1080326219153552745696726677082503956413664431573168030185297215488000000000000000",
+        "1470074081213259807708205673552638474266559428801240677240653541376000000000000",
+        "-450741055908020775777391148239027791615275928530649110728394483712000000000",
+        "45536837777977555898687922539665926283646055703793757394000675776000000",
+        "-127519101991376657036540211752734421820581424000",
+        "1",
+    ]),
+    (-1203, &[
+        "1082015346905596190388342899749769814037859897015446430092886016000000000000000000",
+        "6914629728705193455053225872669379597837205042406801899446599680000000000000000",
+        "41432297436623760923570057622637550238668775532993425711720038400000000000000",
+        "-12963107975090134462022812660336927551359334411701214605475840000000000",
+        "1249014691659202243911946754435591308010786179695192834048000000",
+        "210099810501223851312682035089317344313896960000",
+        "1",
+    ]),
+    (-1219, &[
+        "-5842630610265546171446025137462133609903618721117876319915867425085998497792",
+        "86025026984825992238928438037907605220882432118830009032833545345553137664",
+        "18335328381057829127653144709396490133306754981018761537416243921813504",
+        "4903594828850368686510646252265602134391201975822526498112333152256",
+        "-2356601808633740746787681334664247878133470139102798020608",
+        "432595789637492816207343163042339197660135358464",
+        "1",
+    ]),
+    (-1267, &[
+        "41641164811798159687111097207495461601511274727866368000000000000000000",
+        "-61457144594568911784638644004143969298883726431748096000000000000000",
+        "25393048043513374925275960224049028869761536870055936000000000000",
+        "-1212907169067261713451014962669956980586524568453120000000000",
+        "31680022302665703779281561303635807117209940000768000000",
+        "3671970425506731602488773303126170976367632384000",
+        "1",
+    ]),
+    (-1315, &[
+        "31251571605786539236115581037331124154751650014234887030525092761643778048000000000",
+        "788909147853118458299330252176783793816920523835835908921279818131046400000000",
+        "32064230027367107335157347451358070703360770776900834007428662034432000000",
+        "-4948610872256928975405274872974679679022101245074504200853913600000",
+        "234624683948394811777250424962648197903308562001408557056000",
+        "29942115328974511819983335920363596143051602329600",
+        "1",
+    ]),
+    (-1323, &[
+        "-72439556854674001987775810026867243692368764040380416000000000000000000",
+        "160646066542491131624777841902946733373266611306233856000000000000000",
+        "24790720034570729021150166040824934325726349537312768000000000000",
+        "4449399299679990355983977223021057142086975948324864000000000",
+        "-13649922805621928254271947049916308437038520074240000000",
+        "42320664238412524773562978234934635103614353408000",
+        "1",
+    ]),
+    (-1347, &[
+        "967532855310961934347875936063699390475423017669252414977217524662272000000000000000000",
+        "-1456374759760488414800362195901333580223450443497197790164374100377600000000000000000",
+        "1183528878500783495853618069837929217014996033765883119985965119045632000000000000",
+        "103639283096178207036911521742511340431747893774436991103674613760000000000",
+        "5837261284776421148760695036466761809536118049097250473246720000000",
+        "118756352448741769642769440612484278574983434240000",
+        "1",
+    ]),
+    (-1363, &[
+        "-61478122816559617874801094004621892133458751449853660758016000000000000000000",
+        "89683555152843744472071241545062362107730983259913431547904000000000000000",
+        "-4349577227353230343210559398325533683875804151269484920832000000000000",
+        "58060208975832929889852651167990014185834763388951986176000000000",
+        "-1660322544422636423379846230243523338529861638553600000000",
+        "235059877328812462787270454241538268504455626752000",
+        "1",
+    ]),
+    (-1432, &[
+        "1017363994814577240421026817418452807179749062333264460033172733502588977152000000000000000000",
+        "-1098704264123732131212193591122149705387769398148232104895611176277060550656000000000000000",
+        "638138760721657920366751035779504520066933841824249493685737521692929486848000000000000",
+        "17166275542357183852220728407748455306244780065346610129475027465524828160000000000",
+        "279020588065753321124597808265400844031464533956585302760260064754467776000000",
+        "-4269963537479460404026062619741702395527185137456000",
+        "1",
+    ]),
+    (-1563, &[
+        "911117845086375875023097329777601952853998940113375012207584134299648000000000000000000",
+        "-759085032445061540449808744300679851794433087689797198590650890584064000000000000000",
+        "160751816249029592886668561036804121827461172730387368506958851080192000000000000",
+        "-2314021489973021006909674633799769456496976796530529153284887805952000000000",
+        "832630358471054404769157558327998916860657859855158261373751787520000000",
+        "871644072641038723271254278753152228012083268861952000",
+        "1",
+    ]),
+    (-1588, &[
+        "-752137845086560071769242284465766606476342485056636077644022839057445394584272893640704000000000000000000",
+        "60195369717995194299765194704687291551287615796192537870780377606698912826370781544448000000000000000",
+        "-12280966359713199719188969559666103539924037476829303011261871571442431686156252155904000000000000",
+        "-378335315410755116980846930665131165808873272109042275761349284670644093689845760000000000",
+        "-3589374606091040963085179205989698322613665725980213008521504909876182609984000000",
+        "-2344296418042625602896648055533516832503437774872272000",
+        "1",
+    ]),
+    (-1603, &[
+        "627109133600030028142177582970827323412605557499695827255296000000000000000000000",
+        "-60480669410529354812812065502423560797385816674962705427202048000000000000000",
+        "2568459135239962459516949268334734895309288870551797782020096000000000000",
+        "-14263511902167067342306592426922483809118337482052432035840000000000",
+        "268886131223053886910893524650123111359741795969122959360000000",
+        "4228577613077642052745258882473091413893367342170112000",
+        "1",
+    ]),
+    (-1612, &[
+        "2317942391860066954754945406098987831127459481358788701076652023012793640309312000000000000000000",
+        "-155835745506112148554903463491994428014358566869455027451728851192204026163264000000000000000",
+        "1445263336142341892076638685381948956079310803099408183172268860585529680368000000000000",
+        "-14756809207502232042983737077742071377819813230486549694568337137668306400000000000",
+        "598179490965305476614628134816774447312739865219595996000000",
+        "-6016283711133122621257776576325578228411322362102084000",
+        "1",
+    ]),
+    (-1675, &[
+        "-416157805849154826005910122101916634240040968455930675065497059328000",
+        "78007263835917224223637743991660347853338389729938751279736029184000",
+        "39577325945461007595991543188572217587325253585876709253788467200",
+        "365139487325146129271611658291681014072699664148923845233868800",
+        "-256288090150097137368400692657425009522588656589597111746560",
+        "69104900975525017325515326105265286438319262062385889280",
+        "1",
+    ]),
+    (-1708, &[
+        "994192341080313317000893402756351762952621111234628991660238911716455990939112000000000000000000000",
+        "948996843580871981636264900800576860525196901375826716015636822099469486638559168000000000000000",
+        "432109859683880457245237336392864135003226663676624706691014812352286270776660464000000000000",
+        "-3804785583592961074802231907033749234792264713656916310537561366291594837472000000000",
+        "27679387156321086127604614619760385615628427815972422705884000000",
+        "-243717543219509240868385073078378209964919214569161028000",
+        "1",
+    ]),
+    (-1843, &[
+        "1022305665261405789362573887281623554836961835601464130879356928000000000000000000",
+        "1703315687836999454462902705544451532759249442114228547496181760000000000000000",
+        "65803232285668613475104046006381373299270829927678317718142976000000000000",
+        "1933896614861732326681521305766136783111159908701140030586880000000000",
+        "7838335171913679781647841420995122015074838433940560150528000000",
+        "37403894180202678155356755976563481526943814605112504320000",
+        "1",
+    ]),
+    (-1915, &[
+        "456054159537444194212412319784978746670466288075756158380806269073727997083648000000000",
+        "955039752175865038518068452582314127774856229032417567234553777376498129305600000000",
+        "663390741827053666634329253765141561972318116625293027983875501308124332032000000",
+        "-26067037587498062947818338641678342777412546888862528797003763522365030400000",
+        "443913529284034310464735952592501145675272880020032207103765780627456000",
+        "508255408855221387252483812965750096617531678810798573158400",
+        "1",
+    ]),
+    (-1963, &[
+        "422574183089897680993341868384579111278985397481264634217306904003084288000000000000000000",
+        "-168280159147819662631541114368157506731851183877818745600964405588131840000000000000000",
+        "19059668030708404214628089603489170588607044373819525852869528551161856000000000000",
+        "526162909745086246983617441331479464568236789741451254071906942320640000000000",
+        "1518056825015932290654595442833580616566509313889830333734453248000000",
+        "2816602747966722384292456109932330141692178657723659018240000",
+        "1",
+    ]),
+    (-2227, &[
+        "28720631316224774123307666252767008222477866535756939624181661696000000000000000000",
+        "261956092796420413099089855619865674232266250871471722047878463488000000000000000",
+        "1117562314448896028873037128320051953679946267733531638662510411776000000000000",
+        "179677092901233078376451780209000783317926213276608629696154828800000000000",
+        "-3167872386034517628603508442801962844741316143930251578791952384000000",
+        "24345691790686041024307812988315229670534281342927768410779648000",
+        "1",
+    ]),
+    (-2283, &[
+        "2785697544136965162387394072420723630688138362481979594882244140111963949043943078887424000000000000000000",
+        "1716198832407149390142230898029767593989849732884797744439502301315597060703112342274048000000000000000",
+        "595720153990123108249414034049306230614826738750085234614994400505924870613277555556352000000000000",
+        "586214303394503164685048660501020600138153720498510345640905849705825978399057772544000000000",
+        "834127837386049541614414002772005990776064591087700696685710203969678685910335488000000",
+        "155211702038184330803145503259654912289657012240057832249131008000",
+        "1",
+    ]),
+    (-2403, &[
+        "24279119730911572401603463947085851076637243613288623331619120173023232000000000000000000",
+        "7786057051615269232815758297734073317959461324692935887614063838817681408000000000000000",
+        "-11874884954050127619173400514896650801018238433895901838685061902682292224000000000000",
+        "5559748699025181480926702161852171591222647661089846990718237507678371840000000000",
+        "-205921970353992165278585586660540390520273422573683799918287706390528000000",
+        "7625704243942351617426385884534079265889040465842639422267977728000",
+        "1",
+    ]),
+    (-2443, &[
+        "1091141467717850568672979128010507742016030650424992197403128062629904384000000000000000000000",
+        "-1303075637569920638823636626357675640545850735224562636245543475075002400768000000000000000",
+        "2830628238191940143635407775581195163394092966209234143724725659872427769856000000000000",
+        "-35392782910988048306790135198049515274150075469203806953714502351930261504000000000",
+        "117601856069354919757831592900055106746498198166118083406444614561824768000000",
+        "27329963093974614764335693952290587215038951361905284668516892672000",
+        "1",
+    ]),
+    (-2515, &[
+        "4542025682001712314257239669798903836427850081974322640019291191483010335933953132317007413248000000000",
+        "-784644786861482921129467981036881479240730298368490326394432485672646287230039000718979891200000000",
+        "34524264893265272380279321615950864937352546831170702124352655476891149091055251354550272000000",
+        "35508022560279525502093915946881957463667854787274639048203126213271422670327303372800000",
+        "12817452553049467040241755058889739973324654809101647659200465239853072411262976000",
+        "264952534336082861130132854408232794590765653659767680784931099443200",
+        "1",
+    ]),
+    (-2563, &[
+        "102129587663985724287806992489561567577846558863500706855006254743420928000000000000000000",
+        "58864774134339111721427554723408681524548474869863918839124022121201664000000000000000",
+        "274597348065990355539342208212141816548455550122244719825808530813747200000000000000",
+        "45746741444785974725001917837507456131544792405121928160749594381123584000000000",
+        "2274186539352606939662866985000267388597468055648246852970041520947200000000",
+        "1183114456005657497289765152791754822505422898489194494502849581056000",
+        "1",
+    ]),
+    (-2608, &[
+        "327451677250026694198133278336402500637269636726169887842595798606445909367058576435277640625000000000000",
+        "-700265800610377949731030701279743787907896705372015247301698864378963568331565476375000000000000",
+        "3160150517834696901784153329054103916683908447320628422609243420587305378116705388187500000000",
+        "-3711837295929728841959711983585876317003061224025827146858434575359795370054804675000000000",
+        "1247257156019654977752984724237035223986874495851369721538788760906203681342078134750000",
+        "-4750778730825177725463920948909726618214491718039471628856160047142000",
+        "1",
+    ]),
+    (-2787, &[
+        "15884985100445268357953590178542974707070315641086015871581915377130411370815316912360128512000000000000000000000",
+        "-5235644766151628118258097105317029735516395462631368018318018234267002950529128519059000786944000000000000000",
+        "784947499401654518044866033516003257154294406573276984526494194216920292229127924374648651776000000000000",
+        "-49440523129472948316992499986015196039816359049791059405468009768469693387316640185053085696000000000",
+        "1090494707028478138412734767068970391852526831578865754532271533947768540600626516003389440000000",
+        "1067130840769367449760459178094245062365277165359712626201706039537664000",
+        "1",
+    ]),
+    (-2923, &[
+        "-107647076004054618899149885041577023508926704841725337605266619477543174918897664000000000000000000",
+        "774363313119818364121689883356346187869427427850739895784313136065600552976777216000000000000000",
+        "-66955496044443649365534208670381393264833976438822919881785746085233630904320000000000000000",
+        "1505139645470342989281445816047836400962568733755304379307218827715771646345216000000000",
+        "-86792263080787259059639314792679999399343245671650982657822521515082514432000000",
+        "58170379961665933240578203738471180381102882882017892163204624801890304000",
+        "1",
+    ]),
+    (-3235, &[
+        "647998277128630934905528494533858173315158835269437613982221212986212106082648824126535419035648000000000",
+        "-3837772190384174353594620064753582227990053693883014374622919081037929178581133263075623200358400000000",
+        "7192263040462510544812655375963615437295581516389952397571806265542850848275388815305030500352000000",
+        "-1387819075502669327057862632006931040282860109225026692976122845038485462488175454823278182400000",
+        "1324523129645399278888833215516503732914293300357951568024517203698141584020004078947926016000",
+        "399683563549932602597361857267169263305032254313922171606427519774243079782400",
+        "1",
+    ]),
+    (-3427, &[
+        "41005049699615501580262746280591286724756293222611387574463862065355396705771585536000000000000000000000",
+        "-75742987193074954549516480887286693177380356034217005450634894194742904001009115201536000000000000000",
+        "15729279929560852321792658248308207792829351873300854574540839722606924653615078440960000000000000",
+        "24743287106170989536231277513205873274644847426761693088916137772188765436824109711360000000000",
+        "1126968954187818624768028444963457171002127403385825530682138868009572090880458752000000",
+        "74368468899753921102357820012319793706917067678323056124160605255662601560064000",
+        "1",
+    ]),
+    (-3523, &[
+        "1891122806049482188514552149287232310600359993422396227441476042054348032916807401603072000000000000000000",
+        "-82247488923287229531150741683142374034907128262139951708980106393835613220752324558848000000000000000",
+        "5739878937845904634249975613339469092974656114656775376299207901959336739709260922880000000000000",
+        "-7929778412247870929152470621542619172819940330426296120077327115372385244914122752000000000",
+        "1621093261406839476332521774666989768745148046837678486248143688372406996141342720000000",
+        "960227856952599117407403083978278960265716403943018363363655618844711779688448000",
+        "1",
+    ]),
+    (-3763, &[
+        "-1972044695749344957264022034691792181442667611569357966380899673824002276790894592000000000000000000",
+        "296707598836632495128918950900013013281013148621603981745478288082254158584872960000000000000000",
+        "-14491398267845174510353623140065250272686146261309430483935764832447043772694396928000000000000",
+        "18175400898403039692661146938837767165517241925034331232150383316519417685213184000000000000",
+        "-5305475799937132710035993540311397319391357209546080621122948378132112502801039360000000",
+        "495841562983804298889512601912220400193253241353593591180400260580212099305840640000",
+        "1",
+    ]),
+    (-4075, &[
+        "327449218015598326103658292188693405775089450022293408267511192500873413185838399911936381646297628672000",
+        "307678260252363847142518557058020616760886195569701698589818485393365264653517720247611686912000000",
+        "-2530123180606721645060899046691076382780085382180063288738379701466385821402803681978244451532800",
+        "5676192994124845302961495367285934992661012738565045163590318545337507001358527681694321868800",
+        "-4639796612478190683980432654525770873723843904020069491990531249067242924053663574455746560",
+        "1247257156019637304856107520018074552566824585862995272173368815794085495792299621089280",
+        "1",
+    ]),
+];
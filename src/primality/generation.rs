@@ -0,0 +1,242 @@
+// Searching for primes: the candidates of a window are sieved by small primes first,
+// and only the survivors get a BPSW test.
+use num::CheckedAdd;
+
+use super::*;
+use super::probabilistic::bpsw_primality_test;
+use crate::sieve::primes_up_to;
+use crate::utils::{mod_inverse, mul_mod};
+
+// Below this many bits the random primes are drawn from the list of all of them.
+const SMALL_BITS: usize = 8;
+
+fn int<T: Int>(n: u64) -> T {
+    T::from_u64(n).expect("constant fits into T")
+}
+
+// 2^k
+fn power_of_two<T: Int>(k: usize) -> T {
+    (0..k).fold(T::one(), |x, _| x.clone() + x)
+}
+
+fn is_probable_prime<T: Int>(n: &T) -> bool {
+    !bpsw_primality_test(n.clone()).is_composite()
+}
+
+// Candidates per window, a few times the expected gap between primes of this size
+fn window_len(bits: u64) -> usize {
+    (4 * bits as usize).max(64)
+}
+
+// The sieving primes, more of them for larger candidates where a test costs more
+fn sieving_primes(bits: u64) -> Vec<u64> {
+    primes_up_to((bits * bits / 4).clamp(1 << 8, 1 << 16))
+}
+
+// The candidates base + k step, k < len, that fit into T and are not in a forbidden residue
+// class mod any of the sieving primes, in increasing order. The primes dividing step are
+// skipped, and so is the whole sieve while a candidate might be one of the sieving primes,
+// or twice one of them plus 1.
+fn sieve_window<T: Int + CheckedAdd>(base: &T, step: &T, len: usize, forbidden: &[u64], primes: &[u64]) -> Vec<T> {
+    let mut survivors = vec![true; len];
+    let largest = *primes.last().expect("there are sieving primes");
+    if *base > int(2 * largest + 1) {
+        for &r in primes {
+            let step_r = (step.clone() % int(r)).to_u64().expect("residue fits into u64");
+            if step_r == 0 {
+                continue;
+            }
+            let base_r = (base.clone() % int(r)).to_u64().expect("residue fits into u64");
+            let inverse = mod_inverse(&step_r, &r).expect("r is a prime not dividing step");
+            for &f in forbidden {
+                let k = mul_mod(f % r + r - base_r, inverse, r);
+                for i in (k as usize..len).step_by(r as usize) {
+                    survivors[i] = false;
+                }
+            }
+        }
+    }
+    let mut candidates = Vec::new();
+    let mut candidate = Some(base.clone());
+    for survives in survivors {
+        let Some(c) = candidate else { break };
+        candidate = c.checked_add(step);
+        if survives {
+            candidates.push(c);
+        }
+    }
+    candidates
+}
+
+// The smallest prime larger than n, None past the largest prime in T
+pub fn next_prime<T: Int + CheckedAdd>(n: &T) -> Option<T> {
+    if *n < int(2) {
+        return Some(int(2));
+    }
+    let two: T = int(2);
+    let mut base = n.checked_add(&T::one())?;
+    if base.is_even() {
+        if base == two {
+            return Some(two);
+        }
+        base = base.checked_add(&T::one())?;
+    }
+    let len = window_len(base.bits());
+    let primes = sieving_primes(base.bits());
+    loop {
+        let candidates = sieve_window(&base, &two, len, &[0], &primes);
+        if let Some(p) = candidates.into_iter().find(is_probable_prime) {
+            return Some(p);
+        }
+        base = base.checked_add(&int(2 * len as u64))?;
+    }
+}
+
+// The largest prime smaller than n, None for n <= 2
+pub fn prev_prime<T: Int + CheckedAdd>(n: &T) -> Option<T> {
+    let (two, three): (T, T) = (int(2), int(3));
+    if *n <= two {
+        return None;
+    }
+    if *n == three {
+        return Some(two);
+    }
+    let mut top = n.clone() - T::one();
+    if top.is_even() {
+        top = top - T::one();
+    }
+    let len = window_len(top.bits());
+    let primes = sieving_primes(top.bits());
+    // The windows end at top and go down to 3, after which only 2 is left.
+    while top >= three {
+        let span: T = int(2 * (len as u64 - 1));
+        let base = if top > three.clone() + span.clone() { top.clone() - span } else { three.clone() };
+        let count = ((top.clone() - base.clone()) / two.clone()).to_usize().expect("the window is short") + 1;
+        let candidates = sieve_window(&base, &two, count, &[0], &primes);
+        if let Some(p) = candidates.into_iter().rev().find(is_probable_prime) {
+            return Some(p);
+        }
+        if base == three {
+            break;
+        }
+        top = base - two.clone();
+    }
+    Some(two)
+}
+
+// A prime of exactly `bits` bits chosen at random among those satisfying `accept`, for small bits
+fn small_random_prime<T, R, F>(bits: usize, rng: &mut R, accept: F) -> Option<T> where
+    T: Int, R: Rng + ?Sized, F: Fn(u64) -> bool {
+    let low = 1u64 << (bits - 1);
+    let primes: Vec<u64> = crate::sieve::primes_in(low..2 * low).filter(|&p| accept(p)).collect();
+    if primes.is_empty() {
+        return None;
+    }
+    Some(int(primes[rng.gen_range(0..primes.len())]))
+}
+
+// A random prime p with p = a mod m and 2^(bits - 1) <= p < 2^bits, looked for in a window from a
+// random candidate of the progression. Every other candidate is even when m is odd, so those
+// progressions are stepped by 2m from an odd start.
+fn random_prime_in_progression<T, R, F>(a: &T, m: &T, bits: usize, forbidden: &[u64], rng: &mut R, accept: F) -> Option<T> where
+    T: Int + CheckedAdd, R: Rng + ?Sized, F: Fn(&T) -> bool {
+    let two: T = int(2);
+    let low: T = power_of_two(bits - 1);
+    let high = low.clone() + (low.clone() - T::one());
+    let (mut a, mut step) = (a.mod_floor(m), m.clone());
+    if *m >= low {
+        // At most a and a + m are in range.
+        let candidates: Vec<T> = [Some(a.clone()), a.checked_add(m)].into_iter()
+                                                                   .flatten()
+                                                                   .filter(|c| *c >= low && *c <= high && accept(c))
+                                                                   .collect();
+        let i = rng.gen_range(0..candidates.len().max(1));
+        return candidates.into_iter().nth(i);
+    }
+    if m.is_odd() {
+        if a.is_even() {
+            a = a + m.clone();
+        }
+        step = step * two.clone();
+        a = a.mod_floor(&step);
+    }
+    if a > high {
+        return None;
+    }
+    // The candidates a + k step in range are those with first <= k <= last.
+    let first = if a >= low { T::zero() } else { (low.clone() - a.clone() + step.clone() - T::one()) / step.clone() };
+    let last = (high.clone() - a.clone()) / step.clone();
+    if first > last {
+        return None;
+    }
+    let count = last.clone() - first.clone() + T::one();
+    let primes = sieving_primes(bits as u64);
+    let len = window_len(bits as u64);
+
+    // Few candidates are all tried; otherwise random windows, each one likely to hold a prime
+    if count <= int(4 * len as u64) {
+        let count = count.to_usize().expect("the count is small");
+        let base = a + first * step.clone();
+        let primes_found: Vec<T> = sieve_window(&base, &step, count, forbidden, &primes).into_iter()
+                                                                                       .filter(|c| accept(c))
+                                                                                       .collect();
+        if primes_found.is_empty() {
+            return None;
+        }
+        let i = rng.gen_range(0..primes_found.len());
+        return primes_found.into_iter().nth(i);
+    }
+    loop {
+        let k = T::random_range(rng, &first, &(last.clone() + T::one()));
+        let base = a.clone() + k.clone() * step.clone();
+        let room = ((last.clone() - k) + T::one()).to_usize().map_or(len, |room| room.min(len));
+        if let Some(p) = sieve_window(&base, &step, room, forbidden, &primes).into_iter().find(|c| accept(c)) {
+            return Some(p);
+        }
+    }
+}
+
+// A random prime of exactly `bits` bits
+pub fn random_prime<T, R>(bits: usize, rng: &mut R) -> T where
+    T: Int + CheckedAdd, R: Rng + ?Sized {
+    assert!(bits >= 2, "there are no primes of fewer than 2 bits");
+    if bits <= SMALL_BITS {
+        return small_random_prime(bits, rng, |_| true).expect("there are primes of every size from 2 bits");
+    }
+    random_prime_in_progression(&T::one(), &T::one(), bits, &[0], rng, is_probable_prime)
+        .expect("there are primes of every size")
+}
+
+// A random safe prime p of exactly `bits` bits: (p - 1) / 2 is a prime too.
+// Past 7 these are 11 mod 12, and p - 1 and (p - 1) / 2 rule out the residues 0 and 1 mod every odd prime.
+pub fn random_safe_prime<T, R>(bits: usize, rng: &mut R) -> T where
+    T: Int + CheckedAdd, R: Rng + ?Sized {
+    assert!(bits >= 3, "the smallest safe prime is 5");
+    if bits <= SMALL_BITS {
+        let is_prime = super::deterministic::is_prime;
+        return small_random_prime(bits, rng, |p| is_prime((p - 1) / 2))
+            .expect("there are safe primes of every size from 3 bits");
+    }
+    let accept = |p: &T| is_probable_prime(&((p.clone() - T::one()) / int(2))) && is_probable_prime(p);
+    random_prime_in_progression(&int(11), &int(12), bits, &[0, 1], rng, accept)
+        .expect("there are safe primes of every size")
+}
+
+// A random prime p = a mod m of exactly `bits` bits, None when gcd(a, m) > 1 or there is no such prime
+pub fn random_prime_congruent<T, R>(a: &T, m: &T, bits: usize, rng: &mut R) -> Option<T> where
+    T: Int + CheckedAdd, R: Rng + ?Sized {
+    assert!(bits >= 2, "there are no primes of fewer than 2 bits");
+    assert!(!m.is_zero(), "the modulus is positive");
+    if !gcd(a.mod_floor(m), m.clone()).is_one() {
+        return None;
+    }
+    if bits <= SMALL_BITS {
+        // A modulus past u64 leaves only p = a.
+        let (a, m) = (a.mod_floor(m).to_u64(), m.to_u64());
+        return small_random_prime(bits, rng, |p| match m {
+            Some(m) => Some(p % m) == a,
+            None => Some(p) == a,
+        });
+    }
+    random_prime_in_progression(a, m, bits, &[0], rng, is_probable_prime)
+}
@@ -0,0 +1,166 @@
+// Pratt certificates: n is prime iff some g has order n - 1 modulo n, which is checked
+// with the prime factorization of n - 1, each prime factor carrying its own certificate.
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use super::*;
+use super::deterministic::is_prime;
+use crate::factor::factorize;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PrattCertificate<T> {
+    pub n: T,
+    // a primitive root modulo n
+    pub generator: T,
+    // the prime factors q of n - 1 with their certificates and multiplicities
+    pub factors: Vec<(PrattCertificate<T>, u32)>,
+}
+
+impl<T: Int> PrattCertificate<T> {
+    // Checks the whole certificate, trusting nothing but modular arithmetic.
+    pub fn verify(&self) -> bool {
+        let two = T::one() + T::one();
+        if self.n == two {
+            return self.factors.is_empty();
+        }
+        if self.n < two || self.n.is_even() || self.factors.is_empty() {
+            return false;
+        }
+
+        let n_minus_one = self.n.clone() - T::one();
+        let mut product = T::one();
+        for (certificate, exponent) in &self.factors {
+            if *exponent == 0 || !certificate.verify() {
+                return false;
+            }
+            for _ in 0..*exponent {
+                if certificate.n > n_minus_one.clone() / product.clone() {
+                    return false;
+                }
+                product = product * certificate.n.clone();
+            }
+        }
+        if product != n_minus_one {
+            return false;
+        }
+
+        let g = self.generator.clone();
+        if g.is_zero() || g >= self.n || !mod_exp(g.clone(), n_minus_one.clone(), self.n.clone()).is_one() {
+            return false;
+        }
+        self.factors.iter().all(|(certificate, _)| {
+            let exponent = n_minus_one.clone() / certificate.n.clone();
+            !mod_exp(g.clone(), exponent, self.n.clone()).is_one()
+        })
+    }
+
+    // Every certificate in the tree once, smaller primes first and self last.
+    fn flatten<'a>(&'a self, out: &mut Vec<&'a PrattCertificate<T>>) {
+        if out.iter().any(|certificate| certificate.n == self.n) {
+            return;
+        }
+        for (certificate, _) in &self.factors {
+            certificate.flatten(out);
+        }
+        out.push(self);
+    }
+}
+
+// One line per prime, "n g q1^e1 q2^e2 ...", where every q has a line of its own further up
+// and the last line is the prime being certified. For example, 7 has the certificate
+//     2 1
+//     3 2 2^1
+//     7 3 2^1 3^1
+impl<T: Int> Display for PrattCertificate<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut lines = Vec::new();
+        self.flatten(&mut lines);
+        for (i, certificate) in lines.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{} {}", certificate.n, certificate.generator)?;
+            for (factor, exponent) in &certificate.factors {
+                write!(f, " {}^{}", factor.n, exponent)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseCertificateError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for ParseCertificateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseCertificateError {}
+
+// Parsing only checks the shape of the text, use verify to check the certificate itself.
+impl<T: Int> FromStr for PrattCertificate<T> {
+    type Err = ParseCertificateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut known: Vec<PrattCertificate<T>> = Vec::new();
+
+        for (i, line) in s.lines().map(str::trim).enumerate().filter(|(_, line)| !line.is_empty()) {
+            let error = |message: String| ParseCertificateError{ line: i + 1, message };
+            let number = |word: &str| T::from_str_radix(word, 10)
+                                        .map_err(|_| error(format!("'{}' is not a number", word)));
+
+            let mut words = line.split_whitespace();
+            let n = number(words.next().expect("line is not empty"))?;
+            let generator = number(words.next().ok_or_else(|| error("missing generator".to_string()))?)?;
+
+            let mut factors = Vec::new();
+            for word in words {
+                let (q, exponent) = word.split_once('^')
+                                        .ok_or_else(|| error(format!("'{}' is not of the form q^e", word)))?;
+                let q = number(q)?;
+                let exponent = exponent.parse::<u32>()
+                                       .map_err(|_| error(format!("'{}' is not an exponent", exponent)))?;
+                let certificate = known.iter()
+                                       .find(|certificate| certificate.n == q)
+                                       .ok_or_else(|| error(format!("{} has no certificate above", q)))?;
+                factors.push((certificate.clone(), exponent));
+            }
+            known.push(PrattCertificate{ n, generator, factors });
+        }
+        known.pop().ok_or(ParseCertificateError{ line: 0, message: "empty certificate".to_string() })
+    }
+}
+
+// Builds a certificate for a prime n, None if n is not prime.
+pub fn pratt_certificate(n: u64) -> Option<PrattCertificate<u64>> {
+    if !is_prime(n) {
+        return None;
+    }
+    if n == 2 {
+        return Some(PrattCertificate{ n, generator: 1, factors: Vec::new() });
+    }
+
+    let factors: Vec<(PrattCertificate<u64>, u32)> = factorize(n - 1).into_iter()
+        .map(|(q, e)| (pratt_certificate(q).expect("factors are prime"), e))
+        .collect();
+
+    // A primitive root exists for prime n, and the least one is small.
+    let generator = (2..n).find(|&g| {
+        factors.iter().all(|(certificate, _)| mod_exp(g, (n - 1) / certificate.n, n) != 1)
+    }).expect("prime n has a primitive root");
+
+    Some(PrattCertificate{ n, generator, factors })
+}
+
+// Like deterministic::primality_test, but a prime comes with its Pratt certificate.
+pub fn pratt_primality_test(n: u64) -> Primality<u64> {
+    match pratt_certificate(n) {
+        Some(certificate) => Primality::Prime(Some(Certificate::Pratt(certificate))),
+        None => deterministic::primality_test(n),
+    }
+}
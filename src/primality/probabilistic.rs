@@ -0,0 +1,474 @@
+use super::*;
+use crate::lucas::lucas_sequence;
+
+// n as a T, for small constants
+fn int<T: Int>(n: u64) -> T {
+    T::from_u64(n).expect("constant fits into T")
+}
+
+// Verdict for n < 4 and even n, where there are no bases to test.
+fn trivial_cases<T: Int>(n: &T) -> Option<Primality<T>> {
+    if *n < int(2) {
+        Some(Primality::Composite(Witness::SmallerThanTwo))
+    } else if *n < int(4) {
+        Some(Primality::Prime(Some(Certificate::TrialDivision)))
+    } else if n.is_even() {
+        Some(Primality::Composite(Witness::Factor(int(2))))
+    } else {
+        None
+    }
+}
+
+// Bases for n_iter rounds on odd n > 3, produced one at a time: every base in 2..=n-2
+// when there are at most n_iter of them (the verdict is then exact), otherwise n_iter random ones.
+struct Bases<'a, T, R: ?Sized> {
+    n_minus_one: T,
+    next: T,
+    remaining: usize,
+    exhaustive: bool,
+    rng: &'a mut R,
+}
+
+impl<'a, T: Int, R: Rng + ?Sized> Bases<'a, T, R> {
+    fn new(n: &T, n_iter: usize, rng: &'a mut R) -> Self {
+        let n_minus_one = n.clone() - T::one();
+        // n - 3 bases, compared as u64 since n_iter need not fit into T
+        let exhaustive = matches!((n.clone() - int(3)).to_u64(), Some(count) if count <= n_iter as u64);
+        Bases{ n_minus_one, next: int(2), remaining: n_iter, exhaustive, rng }
+    }
+}
+
+impl<T: Int, R: Rng + ?Sized> Iterator for Bases<'_, T, R> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.exhaustive {
+            if self.next >= self.n_minus_one {
+                return None;
+            }
+            let a = self.next.clone();
+            self.next = a.clone() + T::one();
+            return Some(a);
+        }
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(T::random_range(self.rng, &int(2), &self.n_minus_one))
+    }
+}
+
+pub fn fermat_primality_test<T: Int>(n: T, n_iter: usize) -> Primality<T> {
+    fermat_primality_test_with_rng(n, n_iter, &mut thread_rng())
+}
+
+pub fn fermat_primality_test_with_rng<T, R>(n: T, n_iter: usize, rng: &mut R) -> Primality<T> where
+    T: Int, R: Rng + ?Sized {
+    if let Some(verdict) = trivial_cases(&n) {
+        return verdict;
+    }
+    let ctx = T::Context::new(n.clone());
+    let exponent = n.clone() - T::one();
+    let bases = Bases::new(&n, n_iter, rng);
+    let exhaustive = bases.exhaustive;
+
+    for a in bases {
+        if ctx.pow(&ctx.to_form(a.clone()), &exponent) != ctx.one() {
+            return Primality::Composite(Witness::Base(a));
+        }
+    }
+    if !exhaustive {
+        // Carmichael numbers pass for every coprime base.
+        return Primality::ProbablyPrime{ rounds: n_iter, error_bits: None };
+    }
+    // A base sharing a factor with n would have failed.
+    Primality::Prime(None)
+}
+
+pub fn solovay_strassen_primality_test<T: Int>(n: T, n_iter: usize) -> Primality<T> {
+    solovay_strassen_primality_test_with_rng(n, n_iter, &mut thread_rng())
+}
+
+pub fn solovay_strassen_primality_test_with_rng<T, R>(n: T, n_iter: usize, rng: &mut R) -> Primality<T> where
+    T: Int, R: Rng + ?Sized {
+    if let Some(verdict) = trivial_cases(&n) {
+        return verdict;
+    }
+    let ctx = T::Context::new(n.clone());
+    let exponent = (n.clone() - T::one()) >> 1;
+    let minus_one = ctx.to_form(n.clone() - T::one());
+    let bases = Bases::new(&n, n_iter, rng);
+    let exhaustive = bases.exhaustive;
+
+    for a in bases {
+        let jacobi_ = jacobi(a.clone(), n.clone());
+        if jacobi_ == 0 {
+            return Primality::Composite(Witness::Factor(gcd(a, n)));
+        }
+        let jacobi_symbol = if jacobi_ == 1 { ctx.one() } else { minus_one.clone() };
+
+        if ctx.pow(&ctx.to_form(a.clone()), &exponent) != jacobi_symbol {
+            return Primality::Composite(Witness::Base(a));
+        }
+    }
+    if !exhaustive {
+        // At most half of the bases are Euler liars for a composite n.
+        return Primality::ProbablyPrime{ rounds: n_iter, error_bits: Some(n_iter) };
+    }
+    Primality::Prime(None)
+}
+
+
+// n - 1 = 2^s * n', returns (n', s)
+fn split_n_minus_one<T: Int>(n: &T) -> (T, u64) {
+    let mut s: u64 = 0;
+    let mut n_dash = n.clone() - T::one();
+    while n_dash.is_even() {
+        n_dash = n_dash >> 1;
+        s += 1;
+    }
+    (n_dash, s)
+}
+
+// The strong test to base a, with all arithmetic done in ctx.
+fn strong_test<T: Int, C: ModularContext<T>>(ctx: &C, n_dash: &T, s: u64, a: T) -> bool {
+    let one = ctx.one();
+    let minus_one = ctx.to_form(ctx.modulus() - T::one());
+
+    let mut b = ctx.pow(&ctx.to_form(a), n_dash);
+    if b == one || b == minus_one {
+        return true;
+    }
+    for _ in 1..s {
+        b = ctx.square(&b);
+        if b == minus_one {
+            return true;
+        }
+        if b == one {
+            return false;
+        }
+    }
+    false
+}
+
+// Strong probable-prime test to base a for odd n > 2.
+// Returns false exactly when a is a witness for the compositeness of n.
+pub fn is_strong_probable_prime<T: Int>(n: T, a: T) -> bool {
+    if n < int(3) || n.is_even() {
+        return n == int(2);
+    }
+    let (n_dash, s) = split_n_minus_one(&n);
+    strong_test(&T::Context::new(n), &n_dash, s, a)
+}
+
+
+pub fn miller_rabin_primality_test<T: Int>(n: T, n_iter: usize) -> Primality<T> {
+    miller_rabin_primality_test_with_rng(n, n_iter, &mut thread_rng())
+}
+
+pub fn miller_rabin_primality_test_with_rng<T, R>(n: T, n_iter: usize, rng: &mut R) -> Primality<T> where
+    T: Int, R: Rng + ?Sized {
+    if let Some(verdict) = trivial_cases(&n) {
+        return verdict;
+    }
+    let bases = Bases::new(&n, n_iter, rng);
+    let exhaustive = bases.exhaustive;
+    let (n_dash, s) = split_n_minus_one(&n);
+    let ctx = T::Context::new(n.clone());
+
+    for a in bases {
+        if !strong_test(&ctx, &n_dash, s, a.clone()) {
+            return Primality::Composite(Witness::Base(a));
+        }
+    }
+    if !exhaustive {
+        // At most a quarter of the bases are strong liars for a composite n.
+        return Primality::ProbablyPrime{ rounds: n_iter, error_bits: Some(2 * n_iter) };
+    }
+    Primality::Prime(None)
+}
+
+// Selfridge's method A: the first D in 5, -7, 9, -11, ... with (D / n) = -1.
+// Returns a factor of n instead if some |D| shares one with n.
+// n must be odd and not a perfect square, otherwise the search never ends.
+fn selfridge_parameter<T: Int>(n: &T) -> Result<i64, T> {
+    let mut d: i64 = 5;
+    loop {
+        match jacobi(reduce(d, n), n.clone()) {
+            -1 => return Ok(d),
+            0 if int::<T>(d.unsigned_abs()) != *n => return Err(gcd(int(d.unsigned_abs()), n.clone())),
+            _ => {}
+        }
+        d = if d > 0 { -d - 2 } else { -d + 2 };
+    }
+}
+
+// d mod n for a possibly negative d
+fn reduce<T: Int>(d: i64, n: &T) -> T {
+    let r = int::<T>(d.unsigned_abs()) % n.clone();
+    if d < 0 && !r.is_zero() { n.clone() - r } else { r }
+}
+
+// Strong Lucas probable-prime test with Selfridge's parameters P = 1, Q = (1 - D) / 4.
+pub fn is_strong_lucas_probable_prime<T: Int>(n: T) -> bool {
+    strong_lucas_test(n).is_ok()
+}
+
+fn strong_lucas_test<T: Int>(n: T) -> Result<(), Witness<T>> {
+    if let Some(verdict) = trivial_cases(&n) {
+        return match verdict {
+            Primality::Composite(witness) => Err(witness),
+            _ => Ok(()),
+        };
+    }
+    if is_perfect_square(&n) {
+        return Err(Witness::Factor(n.sqrt()));
+    }
+    let d = selfridge_parameter(&n).map_err(Witness::Factor)?;
+
+    let mul = |a: &T, b: &T| a.mul_mod(b, &n);
+    let add = |a: &T, b: &T| add_mod(a, b, &n);
+    let sub = |a: &T, b: &T| sub_mod(a, b, &n);
+
+    let q = reduce((1 - d) / 4, &n);
+
+    // n + 1 = 2^s * k, k odd. The top bit of n + 1 may not fit into T,
+    // so k is computed from (n + 1) / 2 = (n >> 1) + 1.
+    let mut s: u64 = 1;
+    let mut k = (n.clone() >> 1) + T::one();
+    while k.is_even() {
+        k = k >> 1;
+        s += 1;
+    }
+
+    let (u, mut v, mut q_k) = lucas_sequence(&T::one(), &q, &k, &n);
+
+    if u.is_zero() || v.is_zero() {
+        return Ok(());
+    }
+    for _ in 1..s {
+        v = sub(&mul(&v, &v), &add(&q_k, &q_k));
+        q_k = mul(&q_k, &q_k);
+        if v.is_zero() {
+            return Ok(());
+        }
+    }
+    Err(Witness::Lucas(d))
+}
+
+// Baillie-PSW: a strong test to base 2 followed by a strong Lucas test.
+// There are no BPSW pseudoprimes below 2^64, so there the answer is definite.
+pub fn bpsw_primality_test<T: Int>(n: T) -> Primality<T> {
+    if let Some(verdict) = trivial_cases(&n) {
+        return verdict;
+    }
+    let two: T = int(2);
+    if !is_strong_probable_prime(n.clone(), two.clone()) {
+        return Primality::Composite(Witness::Base(two));
+    }
+    if let Err(witness) = strong_lucas_test(n.clone()) {
+        return Primality::Composite(witness);
+    }
+    if n.to_u64().is_some() {
+        return Primality::Prime(None);
+    }
+    Primality::ProbablyPrime{ rounds: 1, error_bits: None }
+}
+
+// The constant polynomial c
+fn constant<T: Int>(c: T) -> Polynomial<T> {
+    Polynomial{ coeffs: vec![c] }
+}
+
+// p + c mod n
+fn add_constant<T: Int>(mut p: Polynomial<T>, c: &T, n: &T) -> Polynomial<T> {
+    p.coeffs[0] = add_mod(&p.coeffs[0], &c.mod_floor(n), n);
+    p
+}
+
+// p - x mod n
+fn sub_x<T: Int>(p: &Polynomial<T>, n: &T) -> Polynomial<T> {
+    let mut coeffs = p.coeffs.clone();
+    if coeffs.len() < 2 {
+        coeffs.resize(2, T::zero());
+    }
+    coeffs[1] = sub_mod(&coeffs[1], &T::one(), n);
+    Polynomial{ coeffs }
+}
+
+pub fn quadratic_frobenius_test<T: Int>(n: T, n_iter: usize) -> Primality<T> {
+    quadratic_frobenius_test_with_rng(n, n_iter, &mut thread_rng())
+}
+
+// Grantham's bound per round needs n without prime factors up to here.
+const FROBENIUS_TRIAL_DIVISION_BOUND: u64 = 50_000;
+
+// Grantham's Quadratic Frobenius test: every round picks b, c with jacobi(b^2 + 4c, n) = -1
+// and jacobi(-c, n) = 1 and works in (Z/nZ)[x] / (x^2 - bx - c).
+pub fn quadratic_frobenius_test_with_rng<T, R>(n: T, n_iter: usize, rng: &mut R) -> Primality<T> where
+    T: Int, R: Rng + ?Sized {
+    if let Some(verdict) = trivial_cases(&n) {
+        return verdict;
+    }
+    if is_perfect_square(&n) {
+        return Primality::Composite(Witness::Factor(n.sqrt()));
+    }
+    // n is odd here, so odd divisors suffice.
+    for d in (3..FROBENIUS_TRIAL_DIVISION_BOUND).step_by(2) {
+        let d: T = int(d);
+        if d.clone() * d.clone() > n {
+            break;
+        }
+        if (n.clone() % d.clone()).is_zero() {
+            return Primality::Composite(Witness::Factor(d));
+        }
+    }
+
+    for _ in 0..n_iter {
+        let (b, c) = loop {
+            let b = T::random_range(rng, &T::one(), &n);
+            let c = T::random_range(rng, &T::one(), &n);
+            let discriminant = add_mod(&b.mul_mod(&b, &n), &int::<T>(4).mod_floor(&n).mul_mod(&c, &n), &n);
+            let d = gcd(discriminant.clone(), n.clone());
+            if !d.is_one() && d != n {
+                return Primality::Composite(Witness::Factor(d));
+            }
+            let d = gcd(c.clone(), n.clone());
+            if !d.is_one() {
+                return Primality::Composite(Witness::Factor(d));
+            }
+            let minus_c = n.clone() - c.clone();
+            if jacobi(discriminant, n.clone()) == -1 && jacobi(minus_c, n.clone()) == 1 {
+                break (b, c);
+            }
+        };
+        if let Err(witness) = quadratic_frobenius_round(&n, &b, &c) {
+            return Primality::Composite(witness);
+        }
+    }
+    // Without small factors and squares, a composite passes a round with probability
+    // below 1/7710 > 2^-12.
+    Primality::ProbablyPrime{ rounds: n_iter, error_bits: Some(12 * n_iter) }
+}
+
+fn quadratic_frobenius_round<T: Int>(n: &T, b: &T, c: &T) -> Result<(), Witness<T>> {
+    let minus_c = sub_mod(&T::zero(), c, n);
+    let f = Polynomial{ coeffs: vec![minus_c.clone(), sub_mod(&T::zero(), b, n), T::one()] };
+    let witness = || Witness::Polynomial(f.coeffs.clone());
+    let x = Polynomial{ coeffs: vec![T::zero(), T::one()] };
+
+    // x^((n + 1) / 2) lies in Z/nZ and x^(n + 1) = -c.
+    let half_n_plus_one = (n.clone() >> 1) + T::one();
+    let power = x.pow_mod_monic(&half_n_plus_one, &f, n);
+    if power.degree() > 0 || power.mul_mod_monic(&power, &f, n) != constant(minus_c) {
+        return Err(witness());
+    }
+
+    // n^2 - 1 = 2^r s with s odd: x^s = 1 or x^(2^j s) = -1 for some j <= r - 2.
+    let (s_minus, r_minus) = split_n_minus_one(n);
+    let mut s_plus = half_n_plus_one;
+    let mut r_plus = 1;
+    while s_plus.is_even() {
+        s_plus = s_plus >> 1;
+        r_plus += 1;
+    }
+    let mut y = x.pow_mod_monic(&s_minus, &f, n).pow_mod_monic(&s_plus, &f, n);
+    if y == constant(T::one()) {
+        return Ok(());
+    }
+    let minus_one = constant(n.clone() - T::one());
+    for _ in 0..r_minus + r_plus - 1 {
+        if y == minus_one {
+            return Ok(());
+        }
+        y = y.mul_mod_monic(&y, &f, n);
+    }
+    Err(witness())
+}
+
+// Grantham's Frobenius test with respect to a monic f with gcd(n, f(0) disc(f)) = 1.
+// Splitting f mod n into the products F_i of its irreducible factors of degree i,
+// as if n were prime, has to succeed, each F_i(x^n) has to vanish mod F_i, and
+// the number of irreducible factors of even degree has to match jacobi(disc(f), n).
+// For f of degree 0 mod n, or n dividing f(0) disc(f), the test does not apply and the
+// verdict is ProbablyPrime with no rounds and no error bound.
+pub fn frobenius_test<T: Int>(n: T, f: &Polynomial<T>) -> Primality<T> {
+    if let Some(verdict) = trivial_cases(&n) {
+        return verdict;
+    }
+    let witness = || Primality::Composite(Witness::Polynomial(f.coeffs.clone()));
+    let factor = |d: T| Primality::Composite(Witness::Factor(d));
+
+    let inconclusive = Primality::ProbablyPrime{ rounds: 0, error_bits: None };
+    if f.coeffs.is_empty() {
+        return inconclusive;
+    }
+    let f_n = match f.monic_mod(&n) {
+        Ok(f_n) => f_n,
+        // Only f = 0 mod n has a leading coefficient divisible by n.
+        Err(d) if d == n => return inconclusive,
+        Err(d) => return factor(d),
+    };
+    let degree = f_n.degree();
+    if degree < 1 {
+        return inconclusive;
+    }
+    let discriminant = match f_n.discriminant_mod(&n) {
+        Ok(discriminant) => discriminant,
+        Err(d) => return factor(d),
+    };
+    let d = gcd(f_n.coeffs[0].mul_mod(&discriminant, &n), n.clone());
+    if d == n {
+        return inconclusive;
+    }
+    if !d.is_one() {
+        return factor(d);
+    }
+
+    let x = Polynomial{ coeffs: vec![T::zero(), T::one()] };
+    let mut remaining = f_n;
+    let mut x_power = x.clone();
+    let mut even_degree_factors = 0;
+    for i in 1..=degree {
+        if remaining.degree() == 0 {
+            break;
+        }
+        // F_i = gcd(x^(n^i) - x, f / (F_1 ... F_(i-1)))
+        x_power = x_power.rem_monic(&remaining, &n).pow_mod_monic(&n, &remaining, &n);
+        let f_i = match remaining.gcd_mod(&sub_x(&x_power, &n), &n) {
+            Ok(f_i) => f_i,
+            Err(d) => return factor(d),
+        };
+        let degree_i = f_i.degree();
+        if degree_i == 0 {
+            continue;
+        }
+        if degree_i % i != 0 {
+            return witness();
+        }
+        remaining = remaining.div_rem_monic(&f_i, &n).0;
+
+        if i >= 2 {
+            let x_n = x.pow_mod_monic(&n, &f_i, &n);
+            let mut value = constant(T::zero());
+            for c in f_i.coeffs.iter().rev() {
+                value = add_constant(value.mul_mod_monic(&x_n, &f_i, &n), c, &n);
+            }
+            if !value.is_zero() {
+                return witness();
+            }
+        }
+        if i % 2 == 0 {
+            even_degree_factors += degree_i / i;
+        }
+    }
+    if remaining.degree() != 0 {
+        return witness();
+    }
+
+    let expected = if even_degree_factors % 2 == 0 { 1 } else { -1 };
+    if jacobi(discriminant, n.clone()) != expected {
+        return witness();
+    }
+    Primality::ProbablyPrime{ rounds: 1, error_bits: None }
+}
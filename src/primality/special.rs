@@ -0,0 +1,184 @@
+// Tests for numbers of a special form: Mersenne, Fermat, Proth and Riesel numbers.
+use std::ops::Range;
+
+use num::BigUint;
+
+use super::*;
+use super::deterministic::is_prime;
+use crate::montgomery::PlainModulus;
+use crate::lucas::lucas_v;
+
+// Default number of candidates 2kp + 1 tried by mersenne_candidates
+const TRIAL_FACTORING_K: u64 = 1 << 12;
+
+// 2^p - 1
+pub fn mersenne_number(p: u64) -> BigUint {
+    (BigUint::one() << p) - 1u8
+}
+
+// x mod 2^p - 1, using 2^p = 1 so that x = (x >> p) + (x & (2^p - 1)) until x has at most p bits
+fn reduce_mersenne(mut x: BigUint, p: u64, m: &BigUint) -> BigUint {
+    while x.bits() > p {
+        x = (&x >> p) + (x & m);
+    }
+    if x == *m {
+        BigUint::zero()
+    } else {
+        x
+    }
+}
+
+// Lucas-Lehmer: for an odd prime p, 2^p - 1 is prime iff s_(p-2) = 0 modulo 2^p - 1,
+// where s_0 = 4 and s_(i+1) = s_i^2 - 2. A composite exponent p has the factor 2^d - 1
+// for its least prime factor d.
+pub fn lucas_lehmer(p: u64) -> Primality<BigUint> {
+    if p < 2 {
+        return Primality::Composite(Witness::SmallerThanTwo);
+    }
+    if p == 2 {
+        return Primality::Prime(Some(Certificate::TrialDivision));
+    }
+    if let Some(d) = (2..p).take_while(|d| d * d <= p).find(|d| p.is_multiple_of(*d)) {
+        return Primality::Composite(Witness::Factor(mersenne_number(d)));
+    }
+
+    let m = mersenne_number(p);
+    let two = BigUint::from(2u8);
+    let mut s = BigUint::from(4u8);
+    for _ in 0..p - 2 {
+        s = reduce_mersenne(&s * &s + &m - &two, p, &m);
+    }
+    if s.is_zero() {
+        Primality::Prime(None)
+    } else {
+        Primality::Composite(Witness::Residue(s))
+    }
+}
+
+// A proper factor 2kp + 1 of 2^p - 1 with 1 <= k <= max_k, if there is one.
+// Any prime factor q of 2^p - 1 for an odd prime p is 1 mod 2p and +-1 mod 8.
+pub fn trial_factor_mersenne(p: u64, max_k: u64) -> Option<u64> {
+    (1..=max_k).map_while(|k| k.checked_mul(2 * p)?.checked_add(1))
+               .take_while(|&q| p >= 64 || q < (1 << p) - 1)
+               .filter(|q| q % 8 == 1 || q % 8 == 7)
+               .find(|&q| mod_exp(2, p, q) == 1)
+}
+
+// Prime exponents in the range for which 2^p - 1 has no factor 2kp + 1 with k <= max_k.
+// These are the candidates worth a Lucas-Lehmer test.
+pub fn mersenne_candidates(range: Range<u64>, max_k: u64) -> Vec<u64> {
+    range.filter(|&p| is_prime(p) && trial_factor_mersenne(p, max_k).is_none())
+         .collect()
+}
+
+// The exponents p in the range for which 2^p - 1 is prime.
+pub fn mersenne_exponents(range: Range<u64>) -> Vec<u64> {
+    mersenne_candidates(range, TRIAL_FACTORING_K).into_iter()
+                                                 .filter(|&p| lucas_lehmer(p).is_prime())
+                                                 .collect()
+}
+
+// F_m = 2^(2^m) + 1
+pub fn fermat_number(m: u32) -> BigUint {
+    (BigUint::one() << (1u64 << m)) + 1u8
+}
+
+// Pépin: for m >= 1, F_m is prime iff 3^((F_m - 1) / 2) = -1 modulo F_m.
+pub fn pepin(m: u32) -> Primality<BigUint> {
+    let f = fermat_number(m);
+    if m == 0 {
+        return Primality::Prime(Some(Certificate::TrialDivision));
+    }
+    let three = BigUint::from(3u8);
+    let exponent: BigUint = (&f - 1u8) >> 1;
+    if three.modpow(&exponent, &f) == &f - 1u8 {
+        Primality::Prime(None)
+    } else {
+        Primality::Composite(Witness::Base(three))
+    }
+}
+
+// The least a >= 2 with jacobi(a, n) = -1 for an odd n that is not a square,
+// or a nontrivial factor of n met on the way.
+fn quadratic_non_residue(n: &BigUint) -> Result<BigUint, Witness<BigUint>> {
+    for a in 2u32.. {
+        let a = BigUint::from(a);
+        match jacobi(a.clone(), n.clone()) {
+            -1 => return Ok(a),
+            0 if a < *n => return Err(Witness::Factor(gcd(a, n.clone()))),
+            _ => {}
+        }
+    }
+    unreachable!()
+}
+
+// Odd k < 2^e, the shape of the k in Proth and Riesel numbers k 2^e +- 1
+fn is_odd_below_power_of_two(k: u64, e: u64) -> bool {
+    k % 2 == 1 && (e >= 64 || k.ilog2() < e as u32)
+}
+
+// Proth's theorem: for odd k < 2^e, N = k 2^e + 1 is prime iff a^((N - 1) / 2) = -1
+// modulo N for some a, and any quadratic non-residue a will do.
+// None for other k and e, where N is not a Proth number.
+pub fn proth(k: u64, e: u64) -> Option<Primality<BigUint>> {
+    is_odd_below_power_of_two(k, e).then(|| proth_unchecked(k, e))
+}
+
+fn proth_unchecked(k: u64, e: u64) -> Primality<BigUint> {
+    let n = (BigUint::from(k) << e) + 1u8;
+    if is_perfect_square(&n) {
+        return Primality::Composite(Witness::Factor(n.sqrt()));
+    }
+    let a = match quadratic_non_residue(&n) {
+        Ok(a) => a,
+        Err(witness) => return Primality::Composite(witness),
+    };
+    if a.modpow(&(&n >> 1u8), &n) == &n - 1u8 {
+        Primality::Prime(None)
+    } else {
+        Primality::Composite(Witness::Base(a))
+    }
+}
+
+// Lucas-Lehmer-Riesel: for odd k < 2^e, e >= 2, N = k 2^e - 1 is prime iff u_(e-2) = 0
+// modulo N, where u_0 = V_k(P, 1), u_(i+1) = u_i^2 - 2 and P is chosen with
+// jacobi(P - 2, N) = 1 and jacobi(P + 2, N) = -1 (Rödseth).
+// None for other k and e, where the test does not apply.
+pub fn llr(k: u64, e: u64) -> Option<Primality<BigUint>> {
+    (e >= 2 && is_odd_below_power_of_two(k, e)).then(|| llr_unchecked(k, e))
+}
+
+fn llr_unchecked(k: u64, e: u64) -> Primality<BigUint> {
+    let n = (BigUint::from(k) << e) - 1u8;
+    if is_perfect_square(&n) {
+        return Primality::Composite(Witness::Factor(n.sqrt()));
+    }
+
+    let mut parameter = None;
+    for p in 3u32.. {
+        let (below, above) = (BigUint::from(p - 2), BigUint::from(p + 2));
+        for d in [&below, &above] {
+            let g = gcd(d.clone(), n.clone());
+            if !g.is_one() && g != n {
+                return Primality::Composite(Witness::Factor(g));
+            }
+        }
+        if jacobi(below, n.clone()) == 1 && jacobi(above, n.clone()) == -1 {
+            parameter = Some(BigUint::from(p));
+            break;
+        }
+    }
+    let p = parameter.expect("a non-square N has a suitable P");
+
+    let two = BigUint::from(2u8);
+    let ctx = PlainModulus::new(n.clone());
+    let mut u = lucas_v(&ctx, &(p % &n), &BigUint::from(k));
+    for _ in 0..e - 2 {
+        u = (&u * &u + &n - &two) % &n;
+    }
+    if u.is_zero() {
+        Primality::Prime(None)
+    } else {
+        Primality::Composite(Witness::Residue(u))
+    }
+}
@@ -0,0 +1,349 @@
+// A common interface to the primality tests, so that a pipeline can swap and combine them.
+// The integer types a test supports are the T it implements PrimalityTest<T> for.
+use num::BigInt;
+
+use super::*;
+
+// How far a ProbablyPrime verdict of a test can be trusted
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorBound {
+    // the test never returns ProbablyPrime for a composite
+    Exact,
+    // a composite is reported ProbablyPrime with probability at most 2^-bits
+    Bits(usize),
+    // no bound is known
+    Unknown,
+}
+
+impl ErrorBound {
+    // The bound for a composite that has to get past both tests
+    pub fn both(self, other: ErrorBound) -> ErrorBound {
+        match (self, other) {
+            (ErrorBound::Exact, _) | (_, ErrorBound::Exact) => ErrorBound::Exact,
+            (ErrorBound::Bits(a), ErrorBound::Bits(b)) => ErrorBound::Bits(a.max(b)),
+            (ErrorBound::Bits(a), ErrorBound::Unknown) | (ErrorBound::Unknown, ErrorBound::Bits(a)) => ErrorBound::Bits(a),
+            (ErrorBound::Unknown, ErrorBound::Unknown) => ErrorBound::Unknown,
+        }
+    }
+}
+
+pub trait PrimalityTest<T> {
+    fn name(&self) -> String;
+
+    fn test(&self, n: T) -> Primality<T>;
+
+    fn error_bound(&self) -> ErrorBound;
+
+    // This test, and other unless this one settles the question
+    fn then<B: PrimalityTest<T>>(self, other: B) -> Then<Self, B> where Self: Sized {
+        Then(self, other)
+    }
+}
+
+impl<T, P: PrimalityTest<T> + ?Sized> PrimalityTest<T> for Box<P> {
+    fn name(&self) -> String {
+        (**self).name()
+    }
+
+    fn test(&self, n: T) -> Primality<T> {
+        (**self).test(n)
+    }
+
+    fn error_bound(&self) -> ErrorBound {
+        (**self).error_bound()
+    }
+}
+
+// Two ProbablyPrime verdicts for the same n, as one
+fn merge<T>(first: Primality<T>, second: Primality<T>) -> Primality<T> {
+    match (first, second) {
+        (Primality::ProbablyPrime{ rounds: r1, error_bits: e1 },
+         Primality::ProbablyPrime{ rounds: r2, error_bits: e2 }) => {
+            let error_bits = match (e1, e2) {
+                (Some(a), Some(b)) => Some(a.max(b)),
+                (a, b) => a.or(b),
+            };
+            Primality::ProbablyPrime{ rounds: r1 + r2, error_bits }
+        }
+        (_, second) => second,
+    }
+}
+
+pub struct Fermat {
+    pub rounds: usize,
+}
+
+impl<T: Int> PrimalityTest<T> for Fermat {
+    fn name(&self) -> String {
+        format!("Fermat ({} rounds)", self.rounds)
+    }
+
+    fn test(&self, n: T) -> Primality<T> {
+        probabilistic::fermat_primality_test(n, self.rounds)
+    }
+
+    fn error_bound(&self) -> ErrorBound {
+        ErrorBound::Unknown
+    }
+}
+
+pub struct SolovayStrassen {
+    pub rounds: usize,
+}
+
+impl<T: Int> PrimalityTest<T> for SolovayStrassen {
+    fn name(&self) -> String {
+        format!("Solovay-Strassen ({} rounds)", self.rounds)
+    }
+
+    fn test(&self, n: T) -> Primality<T> {
+        probabilistic::solovay_strassen_primality_test(n, self.rounds)
+    }
+
+    fn error_bound(&self) -> ErrorBound {
+        ErrorBound::Bits(self.rounds)
+    }
+}
+
+pub struct MillerRabin {
+    pub rounds: usize,
+}
+
+impl<T: Int> PrimalityTest<T> for MillerRabin {
+    fn name(&self) -> String {
+        format!("Miller-Rabin ({} rounds)", self.rounds)
+    }
+
+    fn test(&self, n: T) -> Primality<T> {
+        probabilistic::miller_rabin_primality_test(n, self.rounds)
+    }
+
+    fn error_bound(&self) -> ErrorBound {
+        ErrorBound::Bits(2 * self.rounds)
+    }
+}
+
+pub struct QuadraticFrobenius {
+    pub rounds: usize,
+}
+
+impl<T: Int> PrimalityTest<T> for QuadraticFrobenius {
+    fn name(&self) -> String {
+        format!("Quadratic Frobenius ({} rounds)", self.rounds)
+    }
+
+    fn test(&self, n: T) -> Primality<T> {
+        probabilistic::quadratic_frobenius_test(n, self.rounds)
+    }
+
+    fn error_bound(&self) -> ErrorBound {
+        ErrorBound::Bits(12 * self.rounds)
+    }
+}
+
+pub struct Frobenius<T> {
+    pub polynomial: Polynomial<T>,
+}
+
+impl<T: Int> PrimalityTest<T> for Frobenius<T> {
+    fn name(&self) -> String {
+        let coeffs: Vec<String> = self.polynomial.coeffs.iter().map(|c| c.to_string()).collect();
+        format!("Frobenius ({})", coeffs.join(", "))
+    }
+
+    fn test(&self, n: T) -> Primality<T> {
+        probabilistic::frobenius_test(n, &self.polynomial)
+    }
+
+    fn error_bound(&self) -> ErrorBound {
+        ErrorBound::Unknown
+    }
+}
+
+pub struct Bpsw;
+
+impl<T: Int> PrimalityTest<T> for Bpsw {
+    fn name(&self) -> String {
+        "BPSW".to_string()
+    }
+
+    fn test(&self, n: T) -> Primality<T> {
+        probabilistic::bpsw_primality_test(n)
+    }
+
+    // No counterexample is known, but there is no proof either.
+    fn error_bound(&self) -> ErrorBound {
+        ErrorBound::Unknown
+    }
+}
+
+// Deterministic Miller-Rabin, u64 only
+pub struct Deterministic;
+
+impl PrimalityTest<u64> for Deterministic {
+    fn name(&self) -> String {
+        "deterministic Miller-Rabin".to_string()
+    }
+
+    fn test(&self, n: u64) -> Primality<u64> {
+        deterministic::primality_test(n)
+    }
+
+    fn error_bound(&self) -> ErrorBound {
+        ErrorBound::Exact
+    }
+}
+
+pub struct Aks;
+
+impl PrimalityTest<u64> for Aks {
+    fn name(&self) -> String {
+        "AKS".to_string()
+    }
+
+    fn test(&self, n: u64) -> Primality<u64> {
+        deterministic::aks(n)
+    }
+
+    fn error_bound(&self) -> ErrorBound {
+        ErrorBound::Exact
+    }
+}
+
+pub struct Pratt;
+
+impl PrimalityTest<u64> for Pratt {
+    fn name(&self) -> String {
+        "Pratt".to_string()
+    }
+
+    fn test(&self, n: u64) -> Primality<u64> {
+        pratt::pratt_primality_test(n)
+    }
+
+    fn error_bound(&self) -> ErrorBound {
+        ErrorBound::Exact
+    }
+}
+
+// Pocklington-Lehmer and BLS, ProbablyPrime when n - 1 cannot be factored far enough
+pub struct Pocklington;
+
+impl<T: Int> PrimalityTest<T> for Pocklington {
+    fn name(&self) -> String {
+        "Pocklington".to_string()
+    }
+
+    fn test(&self, n: T) -> Primality<T> {
+        deterministic::pocklington_primality_test(n)
+    }
+
+    fn error_bound(&self) -> ErrorBound {
+        ErrorBound::Unknown
+    }
+}
+
+// ECPP, ProbablyPrime when the search for a certificate gives up
+pub struct Ecpp;
+
+impl PrimalityTest<BigInt> for Ecpp {
+    fn name(&self) -> String {
+        "ECPP".to_string()
+    }
+
+    fn test(&self, n: BigInt) -> Primality<BigInt> {
+        ecpp::ecpp_primality_test(n)
+    }
+
+    fn error_bound(&self) -> ErrorBound {
+        ErrorBound::Unknown
+    }
+}
+
+// Runs the first test, and the second one unless the first one returned a proof
+// either way. Two ProbablyPrime verdicts are merged.
+pub struct Then<A, B>(pub A, pub B);
+
+impl<T: Clone, A: PrimalityTest<T>, B: PrimalityTest<T>> PrimalityTest<T> for Then<A, B> {
+    fn name(&self) -> String {
+        format!("{} then {}", self.0.name(), self.1.name())
+    }
+
+    fn test(&self, n: T) -> Primality<T> {
+        let first = self.0.test(n.clone());
+        if !first.is_probably_prime() {
+            return first;
+        }
+        merge(first, self.1.test(n))
+    }
+
+    fn error_bound(&self) -> ErrorBound {
+        self.0.error_bound().both(self.1.error_bound())
+    }
+}
+
+// Runs the tests in order and stops at the first proof of primality or compositeness.
+pub struct FirstProof<T> {
+    pub tests: Vec<Box<dyn PrimalityTest<T>>>,
+}
+
+impl<T: Clone> PrimalityTest<T> for FirstProof<T> {
+    fn name(&self) -> String {
+        let names: Vec<String> = self.tests.iter().map(|test| test.name()).collect();
+        format!("first proof of [{}]", names.join(", "))
+    }
+
+    fn test(&self, n: T) -> Primality<T> {
+        let mut verdict = Primality::ProbablyPrime{ rounds: 0, error_bits: None };
+        for test in &self.tests {
+            verdict = merge(verdict, test.test(n.clone()));
+            if !verdict.is_probably_prime() {
+                break;
+            }
+        }
+        verdict
+    }
+
+    fn error_bound(&self) -> ErrorBound {
+        self.tests.iter().fold(ErrorBound::Unknown, |bound, test| bound.both(test.error_bound()))
+    }
+}
+
+// Runs every test and goes with the majority, a tie counting as composite.
+// The verdict returned is that of the first test on the winning side.
+pub struct MajorityVote<T> {
+    pub tests: Vec<Box<dyn PrimalityTest<T>>>,
+}
+
+impl<T: Clone> PrimalityTest<T> for MajorityVote<T> {
+    fn name(&self) -> String {
+        let names: Vec<String> = self.tests.iter().map(|test| test.name()).collect();
+        format!("majority of [{}]", names.join(", "))
+    }
+
+    fn test(&self, n: T) -> Primality<T> {
+        let verdicts: Vec<Primality<T>> = self.tests.iter().map(|test| test.test(n.clone())).collect();
+        let composite = verdicts.iter().filter(|verdict| verdict.is_composite()).count();
+        if 2 * composite >= verdicts.len() {
+            return verdicts.into_iter()
+                           .find(|verdict| verdict.is_composite())
+                           .unwrap_or(Primality::ProbablyPrime{ rounds: 0, error_bits: None });
+        }
+        match verdicts.iter().position(|verdict| verdict.is_prime()) {
+            Some(i) => verdicts.into_iter().nth(i).expect("position is in range"),
+            None => verdicts.into_iter()
+                            .filter(|verdict| verdict.is_probably_prime())
+                            .reduce(merge)
+                            .expect("the majority is probably prime"),
+        }
+    }
+
+    // A vote is only as good as its tests; it is exact when every test is.
+    fn error_bound(&self) -> ErrorBound {
+        if self.tests.iter().all(|test| test.error_bound() == ErrorBound::Exact) {
+            ErrorBound::Exact
+        } else {
+            ErrorBound::Unknown
+        }
+    }
+}
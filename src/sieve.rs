@@ -0,0 +1,250 @@
+// Sieves of Eratosthenes on a mod 30 wheel: one byte covers 30 consecutive integers,
+// with a bit for each of the 8 residues coprime to 30.
+use std::ops::Range;
+
+use crate::primality::deterministic::is_prime;
+
+const WHEEL: u64 = 30;
+
+const RESIDUES: [u64; 8] = [1, 7, 11, 13, 17, 19, 23, 29];
+
+// The bit of each residue mod 30, NONE for residues sharing a factor with 30
+const NONE: u8 = u8::MAX;
+const BIT: [u8; 30] = [
+    NONE, 0, NONE, NONE, NONE, NONE, NONE, 1, NONE, NONE, NONE, 2, NONE, 3, NONE,
+    NONE, NONE, 4, NONE, 5, NONE, NONE, NONE, 6, NONE, NONE, NONE, NONE, NONE, 7
+];
+
+// Bytes per segment of the segmented sieve, about a million integers
+const SEGMENT_BYTES: usize = 1 << 15;
+
+// Segments are sieved by primes up to this bound only; past its square the
+// survivors are confirmed with the deterministic test, which keeps the memory bounded.
+const SIEVING_BOUND: u64 = 1 << 22;
+
+// Clears the multiples p q, q >= p, of the prime p > 5 in the wheel bytes starting at lo,
+// a multiple of 30. Each residue of q mod 30 gives a progression of step p bytes.
+fn cross_off(bytes: &mut [u8], lo: u64, p: u64) {
+    let hi = lo as u128 + WHEEL as u128 * bytes.len() as u128;
+    let q_min = (lo.div_ceil(p)).max(p) as u128;
+    let p = p as u128;
+    if p * q_min >= hi {
+        return;
+    }
+    for r in RESIDUES {
+        let r = r as u128;
+        let q = q_min + (r + WHEEL as u128 - q_min % WHEEL as u128) % WHEEL as u128;
+        let multiple = p * q;
+        if multiple >= hi {
+            continue;
+        }
+        let bit = 1 << BIT[(multiple % WHEEL as u128) as usize];
+        let mut i = ((multiple - lo as u128) / WHEEL as u128) as usize;
+        while i < bytes.len() {
+            bytes[i] &= !bit;
+            i += p as usize;
+        }
+    }
+}
+
+// The integer of byte i and bit b of a sieve starting at lo
+fn value(lo: u64, i: usize, b: u32) -> u64 {
+    lo + WHEEL * i as u64 + RESIDUES[b as usize]
+}
+
+// A bit-packed sieve of the primes up to and including limit
+pub struct Sieve {
+    limit: u64,
+    bytes: Vec<u8>,
+}
+
+impl Sieve {
+    pub fn new(limit: u64) -> Self {
+        let len = (limit / WHEEL + 1) as usize;
+        let mut bytes = vec![u8::MAX; len];
+        // 1 is not a prime
+        bytes[0] &= !1;
+        // Clear the bits past the limit in the last byte
+        for (b, r) in RESIDUES.iter().enumerate() {
+            if WHEEL * (len as u64 - 1) + r > limit {
+                bytes[len - 1] &= !(1 << b);
+            }
+        }
+        let root = limit.isqrt();
+        let mut i = 0;
+        while WHEEL * i as u64 <= root {
+            let mut byte = bytes[i];
+            while byte != 0 {
+                let b = byte.trailing_zeros();
+                byte &= byte - 1;
+                let p = value(0, i, b);
+                if p > root {
+                    break;
+                }
+                cross_off(&mut bytes, 0, p);
+            }
+            i += 1;
+        }
+        Sieve{ limit, bytes }
+    }
+
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    pub fn is_prime(&self, n: u64) -> bool {
+        assert!(n <= self.limit, "{} is past the sieve limit {}", n, self.limit);
+        match n {
+            2 | 3 | 5 => true,
+            _ => {
+                let bit = BIT[(n % WHEEL) as usize];
+                bit != NONE && self.bytes[(n / WHEEL) as usize] & (1 << bit) != 0
+            }
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u64> + '_ {
+        let limit = self.limit;
+        [2, 3, 5].into_iter()
+                 .filter(move |&p| p <= limit)
+                 .chain(self.bytes.iter().enumerate().flat_map(|(i, &byte)| set_bits(byte).map(move |b| value(0, i, b))))
+    }
+
+    pub fn count(&self) -> usize {
+        let small = [2, 3, 5].iter().filter(|&&p| p <= self.limit).count();
+        small + self.bytes.iter().map(|byte| byte.count_ones() as usize).sum::<usize>()
+    }
+}
+
+fn set_bits(mut byte: u8) -> impl Iterator<Item = u32> {
+    std::iter::from_fn(move || {
+        if byte == 0 {
+            return None;
+        }
+        let b = byte.trailing_zeros();
+        byte &= byte - 1;
+        Some(b)
+    })
+}
+
+// All the primes up to and including n
+pub fn primes_up_to(n: u64) -> Vec<u64> {
+    Sieve::new(n).iter().collect()
+}
+
+// The primes in a range, in increasing order, sieved one segment at a time
+pub struct Primes {
+    next: u64,
+    hi: u64,
+    // the primes from 7 up to base_limit, of which those up to sieved_by sieve the segment
+    base: Vec<u64>,
+    base_limit: u64,
+    sieved_by: u64,
+    segment: Vec<u8>,
+    segment_lo: u64,
+    byte: usize,
+    bits: u8,
+}
+
+// Every prime, lazily
+pub fn primes() -> Primes {
+    primes_in(0..u64::MAX)
+}
+
+// The primes p with lo <= p < hi, lazily
+pub fn primes_in(range: Range<u64>) -> Primes {
+    Primes{
+        next: range.start,
+        hi: range.end,
+        base: Vec::new(),
+        base_limit: 0,
+        sieved_by: 0,
+        segment: Vec::new(),
+        segment_lo: 0,
+        byte: 0,
+        bits: 0,
+    }
+}
+
+impl Primes {
+    // Sieves the segment starting at the wheel byte holding next
+    fn sieve_segment(&mut self) {
+        let lo = self.next - self.next % WHEEL;
+        let len = ((self.hi - lo).div_ceil(WHEEL) as usize).min(SEGMENT_BYTES);
+        let top = (lo as u128 + (WHEEL * len as u64) as u128 - 1).min(u64::MAX as u128) as u64;
+        let needed = top.isqrt().min(SIEVING_BOUND);
+        if needed > self.base_limit {
+            self.base_limit = needed.max(2 * self.base_limit).min(SIEVING_BOUND);
+            self.base = Sieve::new(self.base_limit).iter().skip_while(|&p| p < 7).collect();
+        }
+
+        self.segment.clear();
+        self.segment.resize(len, u8::MAX);
+        if lo == 0 {
+            self.segment[0] &= !1;
+        }
+        for &p in self.base.iter().take_while(|&&p| p <= needed) {
+            cross_off(&mut self.segment, lo, p);
+        }
+        self.sieved_by = needed;
+        self.segment_lo = lo;
+        self.byte = 0;
+        self.bits = self.segment[0];
+    }
+
+    // Whether a survivor of the segment sieve is a prime
+    fn confirm(&self, n: u64) -> bool {
+        let bound = self.sieved_by as u128;
+        (n as u128) < (bound + 1) * (bound + 1) || is_prime(n)
+    }
+}
+
+impl Iterator for Primes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        while self.next < self.hi {
+            if self.next < 7 {
+                let n = self.next;
+                self.next += 1;
+                if [2, 3, 5].contains(&n) {
+                    return Some(n);
+                }
+                continue;
+            }
+            let segment_end = self.segment_lo as u128 + (WHEEL * self.segment.len() as u64) as u128;
+            if self.segment.is_empty() || self.next as u128 >= segment_end {
+                self.sieve_segment();
+            }
+            while self.bits == 0 {
+                self.byte += 1;
+                if self.byte == self.segment.len() {
+                    break;
+                }
+                self.bits = self.segment[self.byte];
+            }
+            if self.bits == 0 {
+                let segment_end = self.segment_lo as u128 + (WHEEL * self.segment.len() as u64) as u128;
+                self.next = segment_end.min(self.hi as u128) as u64;
+                continue;
+            }
+            let b = self.bits.trailing_zeros();
+            self.bits &= self.bits - 1;
+            // The last segment below 2^64 has bits for integers past u64::MAX
+            let n = self.segment_lo as u128 + (WHEEL * self.byte as u64 + RESIDUES[b as usize]) as u128;
+            if n >= self.hi as u128 {
+                self.next = self.hi;
+                break;
+            }
+            let n = n as u64;
+            if n < self.next {
+                continue;
+            }
+            self.next = n + 1;
+            if self.confirm(n) {
+                return Some(n);
+            }
+        }
+        None
+    }
+}
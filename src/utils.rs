@@ -1,5 +1,9 @@
-use std::{cmp::min, ops::{Rem, BitAnd, Shr, Shl, Sub, AddAssign, ShrAssign, SubAssign}};
-use num::{Zero, One};
+use std::{cmp::min, fmt::{Debug, Display}, ops::{Rem, BitAnd, Shr, Shl, Sub, AddAssign, ShrAssign, SubAssign}};
+use num::{BigInt, BigUint, FromPrimitive, Integer, ToPrimitive, Zero, One, integer::Roots};
+use num::bigint::RandBigInt;
+use rand::Rng;
+use crate::montgomery::{ModularContext, Montgomery, PlainModulus};
+use crate::sieve::primes_in;
 
 pub fn gcd<T>(mut a: T, mut b: T) -> T where
     T: Rem<Output = T> + Zero + Clone {
@@ -14,7 +18,7 @@ pub fn gcd<T>(mut a: T, mut b: T) -> T where
         b = a % b;
         a = t;
     }
-    return a
+    a
 }
 
 
@@ -65,7 +69,7 @@ pub fn bgcd<T>(mut a: T, mut b: T) -> T where
         }
     }
     // gcd(a, b) = 2^s * gcd(a', b') 
-    return a << s
+    a << s
 }
 
 
@@ -73,95 +77,386 @@ pub fn bgcd<T>(mut a: T, mut b: T) -> T where
 pub fn dumb_mod_exp(a: u64, n: u64, m: u64) -> u64 {
     let mut t = 1;
     for _i in 0..n {
-        t = t * a % m; 
+        t = mul_mod(t, a, m);
     }
-    return t % m;
+    t % m
 }
 
-pub fn mod_exp(a: u64, n: u64, m: u64) -> u64 {
-    let mut k = 0;
-    let mut an = a;
-    let mut result: u64 = 1;
+// a * b mod m, widening through u128 so that no modulus can overflow
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+// a + b mod m for a, b < m, without overflowing near u128::MAX
+fn add_mod_u128(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+// a * b mod m for u128, there is no wider type to go through,
+// so this is the Russian peasant method: double-and-add with reduction at each step.
+pub fn mul_mod_u128(a: u128, b: u128, m: u128) -> u128 {
+    if m <= u64::MAX as u128 {
+        return mul_mod((a % m) as u64, (b % m) as u64, m as u64) as u128;
+    }
+    let mut a = a % m;
+    let mut b = b % m;
+    let mut result: u128 = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod_u128(result, a, m);
+        }
+        a = add_mod_u128(a, a, m);
+        b >>= 1;
+    }
+    result
+}
+
+// Integer types that the modular arithmetic and the primality tests are generic over.
+pub trait Int: Integer + Roots + Clone + Debug + Display + FromPrimitive + ToPrimitive
+               + Shr<usize, Output = Self> {
+    // How to do repeated arithmetic modulo one fixed odd modulus
+    type Context: ModularContext<Self>;
+
+    // a * b mod m for 0 <= a, b < m, without overflowing
+    fn mul_mod(&self, other: &Self, m: &Self) -> Self;
+
+    // number of significant bits
+    fn bits(&self) -> u64;
+
+    fn bit(&self, i: u64) -> bool;
+
+    // a uniformly random integer in [low, high)
+    fn random_range<R: Rng + ?Sized>(rng: &mut R, low: &Self, high: &Self) -> Self;
+
+    fn to_bigint(&self) -> BigInt;
+
+    // None when n does not fit into Self
+    fn from_bigint(n: &BigInt) -> Option<Self>;
+}
+
+macro_rules! impl_int_for_primitive {
+    ($t:ty, $context:ty, $mul_mod:expr) => {
+        impl Int for $t {
+            type Context = $context;
+
+            fn mul_mod(&self, other: &Self, m: &Self) -> Self {
+                $mul_mod(*self, *other, *m)
+            }
+
+            fn bits(&self) -> u64 {
+                (<$t>::BITS - self.leading_zeros()) as u64
+            }
+
+            fn bit(&self, i: u64) -> bool {
+                (self >> i) & 1 == 1
+            }
+
+            fn random_range<R: Rng + ?Sized>(rng: &mut R, low: &Self, high: &Self) -> Self {
+                rng.gen_range(*low..*high)
+            }
+
+            fn to_bigint(&self) -> BigInt {
+                BigInt::from(*self)
+            }
+
+            fn from_bigint(n: &BigInt) -> Option<Self> {
+                <$t>::try_from(n).ok()
+            }
+        }
+    }
+}
+
+impl_int_for_primitive!(u32, PlainModulus<u32>, |a: u32, b: u32, m: u32| ((a as u64 * b as u64) % m as u64) as u32);
+impl_int_for_primitive!(u64, Montgomery<u64>, mul_mod);
+impl_int_for_primitive!(u128, Montgomery<u128>, mul_mod_u128);
+
+impl Int for BigUint {
+    type Context = PlainModulus<BigUint>;
+
+    fn mul_mod(&self, other: &Self, m: &Self) -> Self {
+        self * other % m
+    }
+
+    fn bits(&self) -> u64 {
+        BigUint::bits(self)
+    }
+
+    fn bit(&self, i: u64) -> bool {
+        BigUint::bit(self, i)
+    }
+
+    fn random_range<R: Rng + ?Sized>(rng: &mut R, low: &Self, high: &Self) -> Self {
+        rng.gen_biguint_range(low, high)
+    }
+
+    fn to_bigint(&self) -> BigInt {
+        BigInt::from(self.clone())
+    }
+
+    fn from_bigint(n: &BigInt) -> Option<Self> {
+        n.to_biguint()
+    }
+}
+
+impl Int for BigInt {
+    type Context = PlainModulus<BigInt>;
+
+    fn mul_mod(&self, other: &Self, m: &Self) -> Self {
+        (self * other).mod_floor(m)
+    }
+
+    fn bits(&self) -> u64 {
+        BigInt::bits(self)
+    }
+
+    fn bit(&self, i: u64) -> bool {
+        BigInt::bit(self, i)
+    }
+
+    fn random_range<R: Rng + ?Sized>(rng: &mut R, low: &Self, high: &Self) -> Self {
+        rng.gen_bigint_range(low, high)
+    }
+
+    fn to_bigint(&self) -> BigInt {
+        self.clone()
+    }
+
+    fn from_bigint(n: &BigInt) -> Option<Self> {
+        Some(n.clone())
+    }
+}
+
+// a + b mod m for 0 <= a, b < m
+pub fn add_mod<T: Int>(a: &T, b: &T, m: &T) -> T {
+    let complement = m.clone() - b.clone();
+    if *a >= complement {
+        a.clone() - complement
+    } else {
+        a.clone() + b.clone()
+    }
+}
 
-    while (n >> k) > 0 {
-        if (n >> k) & 1 == 1 {
-            result = (result * an) % m;
+// a - b mod m for 0 <= a, b < m
+pub fn sub_mod<T: Int>(a: &T, b: &T, m: &T) -> T {
+    if a >= b {
+        a.clone() - b.clone()
+    } else {
+        m.clone() - (b.clone() - a.clone())
+    }
+}
+
+pub fn mod_exp<T: Int>(a: T, mut n: T, m: T) -> T {
+    let mut an = a.mod_floor(&m);
+    let mut result = T::one().mod_floor(&m);
+
+    while !n.is_zero() {
+        if n.is_odd() {
+            result = result.mul_mod(&an, &m);
         }
-        an = (an * an) % m;
-        k += 1;
+        an = an.mul_mod(&an, &m);
+        n = n >> 1;
     }
-    return result;
+    result
+}
+
+// n mod 8 for n >= 0
+fn mod_8<T: Int>(n: &T) -> u8 {
+    (n.clone() % T::from_u8(8).expect("8 fits into T")).to_u8().expect("n % 8 fits into u8")
 }
 
 // jacobi(a,n) = (a / n)
-pub fn jacobi(mut a: u64, mut n: u64) -> i8 {
-    if n % 2 == 0 {
+pub fn jacobi<T: Int>(a: T, mut n: T) -> i8 {
+    if n.is_even() {
         panic!("The Jacobi symbol (a / b) is not defined for even b.");
     }
 
-    a = a % n;
+    let mut a = a.mod_floor(&n);
     let mut t: i8 = 1;
-    let mut r: u64;
-    while a > 0 {
-        while a % 2 == 0 {
+    let mut r: u8;
+    while !a.is_zero() {
+        while a.is_even() {
             a = a >> 1; // equivalent to a = a / 2;
-            r = n % 8;
+            r = mod_8(&n);
             if r == 3 || r == 5 {
-                t = -1;
+                t = -t;
             }
         }
-        r = n;
-        n = a;
-        a = r;
-        if a % 4 == 3 && n % 4 == 3 {
+        std::mem::swap(&mut a, &mut n);
+        if mod_8(&a) % 4 == 3 && mod_8(&n) % 4 == 3 {
             t = -t;
         }
-        a = a % n;
+        a = a.mod_floor(&n);
     }
-    if n == 1 {
+    if n.is_one() {
         return t;
     }
-    return 0;
+    0
 }
 
-
 fn bit_length(n: u64) -> u64 {
-    let mut t: u64 = 1;
-    while n >> t != 0 {t += 1;}
-    return t;
+    (64 - n.leading_zeros() as u64).max(1)
 }
 
-// Newton-Raphson
-pub fn is_perfect_kth_power(n: u64, power: u64) -> bool {
-    assert!((n > 1) && (power > 1));
-    let bitlength = bit_length(n);
-    let mut approximation = 2u64.pow(((bitlength + power - 1) / power) as u32);
+// x^k, or None when that is past bound; the partial products never are, so this does not overflow
+fn pow_at_most<T: Int>(x: &T, k: u32, bound: &T) -> Option<T> {
+    let mut power = T::one();
+    for _ in 0..k {
+        if !x.is_zero() && power > bound.clone() / x.clone() {
+            return None;
+        }
+        power = power * x.clone();
+    }
+    Some(power)
+}
+
+// floor(n^(1/k)) by Newton-Raphson, x -> ((k - 1) x + n / x^(k - 1)) / k from a power of two
+// above the root. The iteration decreases until it reaches the integer root, which is at least
+// 2 past the early returns, so that the step stays below k x and does not overflow.
+pub fn integer_root<T: Int>(n: &T, k: u32) -> T {
+    assert!(k > 0, "there is no 0-th root");
+    if n.is_zero() || k == 1 {
+        return n.clone();
+    }
+    // 1 <= n < 2^k
+    if n.bits() <= k as u64 {
+        return T::one();
+    }
+    let k_t = T::from_u32(k).expect("k fits into T");
+    let mut x = (0..n.bits().div_ceil(k as u64)).fold(T::one(), |x, _| x.clone() + x);
     loop {
-        let t: u64 = approximation.pow((power - 1) as u32);
-        let b: u64 = ((power - 1) * approximation * t + n) / (power * t);
-        if approximation <= b {
-            approximation = b;
-            break;
-        } else {
-            approximation = b;
+        let quotient = pow_at_most(&x, k - 1, n).map_or(T::zero(), |power| n.clone() / power);
+        let y = ((k_t.clone() - T::one()) * x.clone() + quotient) / k_t.clone();
+        if y >= x {
+            return x;
         }
+        x = y;
+    }
+}
+
+// The k-th root of n if n is a perfect k-th power
+pub fn exact_root<T: Int>(n: &T, k: u32) -> Option<T> {
+    let root = integer_root(n, k);
+    (pow_at_most(&root, k, n)? == *n).then_some(root)
+}
+
+const fn squares_mod(m: u64) -> u64 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < m {
+        mask |= 1 << (i * i % m);
+        i += 1;
     }
-    if n == approximation.pow(power as u32) {
-        return true;
+    mask
+}
+
+// The residues of squares mod 64 and mod 63, as bit masks
+const SQUARES_MOD_64: u64 = squares_mod(64);
+const SQUARES_MOD_63: u64 = squares_mod(63);
+
+// The square root of n if n is a perfect square. All but about 1 in 21 non-squares
+// are ruled out by their residues mod 64 and 63 before the root is taken.
+pub fn exact_sqrt<T: Int>(n: &T) -> Option<T> {
+    let residue = |m: u64| (n.clone() % T::from_u64(m).expect("m fits into T")).to_u64().expect("a residue fits into u64");
+    if SQUARES_MOD_64 >> residue(64) & 1 == 0 || SQUARES_MOD_63 >> residue(63) & 1 == 0 {
+        return None;
     }
-    return false;
+    exact_root(n, 2)
+}
+
+pub fn is_perfect_kth_power(n: u64, power: u64) -> bool {
+    assert!((n > 1) && (power > 1));
+    exact_root(&n, power as u32).is_some()
+}
+
+
+pub fn is_perfect_square<T>(n: &T) -> bool where
+    T: Roots + Clone {
+    let root = n.sqrt();
+    root.clone() * root == *n
 }
 
 
 pub fn is_perfect_power(n: u64) -> bool {
     let bl = bit_length(n);
 
-    for k in 2..bl + 1 {
-        // We need not check for composite k-s
-        // TODO: check only for precomputed list of primes when possible.
+    // A perfect k-th power is a perfect p-th power for every prime p dividing k.
+    for k in primes_in(2..bl + 1) {
         if is_perfect_kth_power(n, k) {
             return true;            
         }
     }
-    return false;
-}
\ No newline at end of file
+    false
+}
+
+// a^-1 mod m, or Err(gcd(a, m)) when a is not invertible.
+// Extended Euclid, with the coefficient of a kept reduced mod m so that unsigned types work.
+pub fn mod_inverse<T: Int>(a: &T, m: &T) -> Result<T, T> {
+    let (mut old_r, mut r) = (a.mod_floor(m), m.clone());
+    let (mut old_s, mut s) = (T::one(), T::zero());
+    while !r.is_zero() {
+        let (q, remainder) = old_r.div_rem(&r);
+        old_r = std::mem::replace(&mut r, remainder);
+        let new_s = sub_mod(&old_s, &q.mul_mod(&s, m), m);
+        old_s = std::mem::replace(&mut s, new_s);
+    }
+    if old_r.is_one() {
+        Ok(old_s.mod_floor(m))
+    } else {
+        Err(old_r)
+    }
+}
+
+// A square root of a modulo an odd prime p, None if a is a non-residue.
+// Tonelli-Shanks with p - 1 = q 2^s.
+pub fn sqrt_mod<T: Int>(a: T, p: T) -> Option<T> {
+    let a = a.mod_floor(&p);
+    let two = T::one() + T::one();
+    if a.is_zero() || p == two {
+        return Some(a);
+    }
+    if jacobi(a.clone(), p.clone()) != 1 {
+        return None;
+    }
+
+    let mut q = p.clone() - T::one();
+    let mut s = 0;
+    while q.is_even() {
+        q = q >> 1;
+        s += 1;
+    }
+    let mut z = two;
+    while jacobi(z.clone(), p.clone()) != -1 {
+        z = z + T::one();
+    }
+
+    let mut m = s;
+    let mut c = mod_exp(z, q.clone(), p.clone());
+    let mut t = mod_exp(a.clone(), q.clone(), p.clone());
+    let mut root = mod_exp(a, (q + T::one()) >> 1, p.clone());
+    while !t.is_one() {
+        // the least i with t^(2^i) = 1
+        let mut i = 0;
+        let mut power = t.clone();
+        while !power.is_one() {
+            power = power.mul_mod(&power, &p);
+            i += 1;
+            if i == m {
+                // p is not prime after all
+                return None;
+            }
+        }
+        let mut b = c;
+        for _ in 0..m - i - 1 {
+            b = b.mul_mod(&b, &p);
+        }
+        m = i;
+        c = b.mul_mod(&b, &p);
+        t = t.mul_mod(&c, &p);
+        root = root.mul_mod(&b, &p);
+    }
+    Some(root)
+}